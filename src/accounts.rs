@@ -0,0 +1,266 @@
+// Accounts that reserve the names of the players. The server keeps the hashes of the passwords and the tokens on disk,
+// the game keeps the tokens of the earlier logins, so the password is asked only once
+
+use std::fs;
+use std::path::Path;
+
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
+use argon2::password_hash::SaltString;
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+
+/// File of the game with the tokens of the accounts
+pub const TOKEN_FILE : &str = "./tokens.json";
+
+/// The shortest password the server accepts
+pub const MIN_PASSWORD_LEN : usize = 4;
+
+/// Number of the tokens one account keeps, the oldest login is forgotten first
+const MAX_TOKENS : usize = 8;
+
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+/// Registered name, the password itself is never stored
+pub struct Account {
+    pub name : String,
+    /// Argon2 hash of the password with its salt and parameters
+    hash : String,
+    /// SHA-256 hashes of the tokens given to the games that have logged in,
+    /// the file of the accounts doesn't let anybody log in
+    tokens : Vec<String>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+/// All accounts of the server
+pub struct Accounts {
+    pub accounts : Vec<Account>,
+}
+
+
+impl Accounts {
+
+    /// Read the accounts from the json file, there are none if the file doesn't exist yet.
+    /// The password hash that isn't argon2 is an error, the account is never dropped
+    pub fn load(path : &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Accounts::default());
+        }
+        let data = fs::read_to_string(path).map_err(|e| format!("Can't read accounts {}: {}", path.display(), e))?;
+        let accounts : Accounts = serde_json::from_str(&data)
+            .map_err(|e| format!("Can't parse accounts {}: {}", path.display(), e))?;
+        if let Some(account) = accounts.accounts.iter().find(|account| PasswordHash::new(&account.hash).is_err()) {
+            return Err(format!("The account {} in {} has an unknown password hash", account.name, path.display()));
+        }
+        Ok(accounts)
+    }
+
+    /// Write the accounts to the json file
+    pub fn save(&self, path : &Path) -> Result<(), String> {
+        let data = serde_json::to_string_pretty(self).expect("Can't serialize accounts");
+        fs::write(path, data).map_err(|e| format!("Can't write accounts {}: {}", path.display(), e))
+    }
+
+    /// Check if somebody owns the name
+    pub fn is_registered(&self, name : &str) -> bool {
+        self.accounts.iter().any(|account| account.name == name)
+    }
+
+    /// Check the name and the password of the new account, the password is hashed later in `Credentials::check`
+    pub fn begin_register(&self, name : &str, password : &str) -> Result<Credentials, String> {
+        if name.is_empty() {
+            return Err("The account needs a name".to_string());
+        }
+        if password.len() < MIN_PASSWORD_LEN {
+            return Err(format!("The password needs at least {} characters", MIN_PASSWORD_LEN));
+        }
+        if self.is_registered(name) {
+            return Err(format!("The name {} is already registered", name));
+        }
+        Ok(Credentials::NewPassword(password.to_string()))
+    }
+
+    /// Check the token of the account, the password is checked later in `Credentials::check`
+    /// against the copy of the stored hash
+    pub fn begin_login(&self, name : &str, password : Option<&str>, token : Option<&str>) -> Result<Credentials, String> {
+        let account = self.accounts.iter()
+            .find(|account| account.name == name)
+            .ok_or_else(|| format!("There is no account {}", name))?;
+
+        let known_token = |token : &&str| {
+            let hash = token_hash(token);
+            account.tokens.iter().any(|known| same_secret(known, &hash))
+        };
+        if let Some(token) = token.filter(known_token) {
+            return Ok(Credentials::Token(token.to_string()));
+        }
+        match password {
+            Some(password) => Ok(Credentials::Password { hash : account.hash.clone(), password : password.to_string() }),
+            None => Err("The saved login has expired, please enter the password".to_string()),
+        }
+    }
+
+    /// Take the checked login and return the token the game keeps. The new account gets its first token,
+    /// the password gives a new token, the token stays the same
+    pub fn commit(&mut self, name : &str, checked : Checked) -> Result<String, String> {
+        match checked {
+            Checked::Token(token) => Ok(token),
+            // somebody else may have registered the name while the password was hashed
+            Checked::NewAccount { hash } => {
+                if self.is_registered(name) {
+                    return Err(format!("The name {} is already registered", name));
+                }
+                let token = random_hex();
+                self.accounts.push(Account { name : name.to_string(), hash, tokens : vec![token_hash(&token)] });
+                Ok(token)
+            }
+            Checked::Password => {
+                let account = self.accounts.iter_mut()
+                    .find(|account| account.name == name)
+                    .ok_or_else(|| format!("There is no account {}", name))?;
+                let token = random_hex();
+                account.tokens.push(token_hash(&token));
+                if account.tokens.len() > MAX_TOKENS {
+                    account.tokens.remove(0);
+                }
+                Ok(token)
+            }
+        }
+    }
+}
+
+
+/// Login that still needs argon2. The server runs it without holding its state,
+/// so the other clients don't wait for the slow hash
+pub enum Credentials {
+    /// The token of an earlier login is right, nothing is left to check
+    Token(String),
+    /// The password of the new account
+    NewPassword(String),
+    /// The password of the account and the copy of its stored hash
+    Password { hash : String, password : String },
+}
+
+
+/// Login after argon2, the accounts take it in `Accounts::commit`
+pub enum Checked {
+    Token(String),
+    NewAccount { hash : String },
+    Password,
+}
+
+
+impl Credentials {
+
+    /// Hash the new password or check the password against the hash
+    pub fn check(self) -> Result<Checked, String> {
+        match self {
+            Credentials::Token(token) => Ok(Checked::Token(token)),
+            Credentials::NewPassword(password) => Ok(Checked::NewAccount { hash : hash_password(&password) }),
+            Credentials::Password { hash, password } if check_password(&hash, &password) => Ok(Checked::Password),
+            Credentials::Password { .. } => Err("Wrong password".to_string()),
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+/// Login of the game to one account of one server
+pub struct SavedToken {
+    pub server : String,
+    pub name : String,
+    pub token : String,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+/// Tokens of the accounts the game has logged in to
+pub struct TokenStore {
+    pub tokens : Vec<SavedToken>,
+}
+
+
+impl TokenStore {
+
+    /// Read the tokens from the json file, the broken or missing file means no tokens
+    pub fn load(path : &Path) -> Self {
+        fs::read_to_string(path).ok()
+            .and_then(|data| serde_json::from_str(&data).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write the tokens to the json file
+    pub fn save(&self, path : &Path) -> Result<(), String> {
+        let data = serde_json::to_string_pretty(self).expect("Can't serialize tokens");
+        fs::write(path, data).map_err(|e| format!("Can't write tokens {}: {}", path.display(), e))
+    }
+
+    /// Token of the name on the server
+    pub fn get(&self, server : &str, name : &str) -> Option<&str> {
+        self.tokens.iter()
+            .find(|saved| saved.server == server && saved.name == name)
+            .map(|saved| saved.token.as_str())
+    }
+
+    /// Remember the token of the name on the server, the older one is replaced
+    pub fn set(&mut self, server : &str, name : &str, token : &str) {
+        self.remove(server, name);
+        self.tokens.push(SavedToken { server : server.to_string(), name : name.to_string(), token : token.to_string() });
+    }
+
+    /// Forget the token of the name on the server
+    pub fn remove(&mut self, server : &str, name : &str) {
+        self.tokens.retain(|saved| saved.server != server || saved.name != name);
+    }
+}
+
+
+/// Argon2 hash of the password with a new random salt
+fn hash_password(password : &str) -> String {
+    let salt = SaltString::encode_b64(&random_bytes()).expect("Can't encode salt");
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .expect("Can't hash password")
+        .to_string()
+}
+
+
+/// Check the password against the stored hash, the hashes are compared in constant time
+fn check_password(hash : &str, password : &str) -> bool {
+    PasswordHash::new(hash)
+        .is_ok_and(|hash| Argon2::default().verify_password(password.as_bytes(), &hash).is_ok())
+}
+
+
+/// SHA-256 of the token in hex, the token is random enough to need no salt
+fn token_hash(token : &str) -> String {
+    to_hex(&Sha256::digest(token.as_bytes()))
+}
+
+
+/// Compare the secrets in constant time, the time of the answer doesn't tell how much of the guess is right
+fn same_secret(known : &str, guess : &str) -> bool {
+    known.as_bytes().ct_eq(guess.as_bytes()).into()
+}
+
+
+/// Random 128 bits from the random source of the system
+fn random_bytes() -> [u8; 16] {
+    let mut bytes = [0; 16];
+    getrandom::getrandom(&mut bytes).expect("Can't read the system random source");
+    bytes
+}
+
+
+/// Random 128 bits in hex for the tokens
+fn random_hex() -> String {
+    to_hex(&random_bytes())
+}
+
+
+/// Bytes in lowercase hex
+fn to_hex(bytes : &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -0,0 +1,134 @@
+use std::time::{Duration, Instant};
+
+use rltk::RandomNumberGenerator;
+use serde::{Serialize, Deserialize};
+
+use crate::{ConnectionStatus, NetworkBackend, ProtocolMessage};
+
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+#[serde(default)]
+/// Bad network the debug transport pretends to be, every message is delayed both ways
+pub struct NetworkConditions {
+    /// Delay of every message
+    pub lag_ms : u64,
+    /// The biggest random delay on top of the lag
+    pub jitter_ms : u64,
+    /// Share of the messages that are lost, from 0 to 1
+    pub drop_rate : f32,
+    /// Share of the messages that are held back behind the next ones, from 0 to 1
+    pub reorder_rate : f32,
+    /// Seed of the delays, the losses and the reordering, the same seed spoils the same messages.
+    /// Every run is different without it
+    pub network_seed : Option<u64>,
+}
+
+
+impl NetworkConditions {
+
+    /// Check if the network is worse than the real one
+    pub fn is_active(&self) -> bool {
+        self.lag_ms > 0 || self.jitter_ms > 0 || self.drop_rate > 0.0 || self.reorder_rate > 0.0
+    }
+}
+
+
+/// Message that waits for its delivery time
+struct Delayed {
+    due : Instant,
+    message : ProtocolMessage,
+}
+
+
+/// Debug transport that wraps the real one and delays, loses and reorders the messages,
+/// so the laggy network of the players can be reproduced on one machine
+pub struct LaggingBackend {
+    inner : Box<dyn NetworkBackend>,
+    conditions : NetworkConditions,
+    rng : RandomNumberGenerator,
+    outgoing : Vec<Delayed>,
+    incoming : Vec<Delayed>,
+}
+
+
+impl LaggingBackend {
+
+    /// Wrap the backend with the given network conditions
+    pub fn new(inner : Box<dyn NetworkBackend>, conditions : NetworkConditions) -> Self {
+        LaggingBackend {
+            inner,
+            conditions,
+            rng : match conditions.network_seed {
+                Some(seed) => RandomNumberGenerator::seeded(seed),
+                None => RandomNumberGenerator::new(),
+            },
+            outgoing : Vec::new(),
+            incoming : Vec::new(),
+        }
+    }
+
+    /// Time the message is delivered at or `None` if it is lost
+    fn schedule(&mut self) -> Option<Instant> {
+        if self.rng.range(0.0, 1.0) < self.conditions.drop_rate {
+            return None;
+        }
+
+        let mut delay = self.conditions.lag_ms;
+        if self.conditions.jitter_ms > 0 {
+            delay += self.rng.range(0, self.conditions.jitter_ms + 1);
+        }
+        // the held back message comes after everything sent within the worst delay
+        if self.rng.range(0.0, 1.0) < self.conditions.reorder_rate {
+            delay += self.conditions.lag_ms + self.conditions.jitter_ms + 1;
+        }
+        Some(Instant::now() + Duration::from_millis(delay))
+    }
+
+    /// Pass the outgoing messages that are due to the real transport
+    fn flush(&mut self) {
+        for delayed in take_due(&mut self.outgoing) {
+            self.inner.send(&delayed.message);
+        }
+    }
+}
+
+
+/// Remove the due messages from the queue in the order of their delivery time
+fn take_due(queue : &mut Vec<Delayed>) -> Vec<Delayed> {
+    let now = Instant::now();
+    let (mut due, waiting) : (Vec<_>, Vec<_>) = queue.drain(..).partition(|delayed| delayed.due <= now);
+    *queue = waiting;
+    due.sort_by_key(|delayed| delayed.due);
+    due
+}
+
+
+impl NetworkBackend for LaggingBackend {
+
+    fn send(&mut self, msg : &ProtocolMessage) {
+        if let Some(due) = self.schedule() {
+            self.outgoing.push(Delayed { due, message : msg.clone() });
+        }
+        self.flush();
+    }
+
+    /// The game asks for the messages every tick, so the outgoing ones are sent from here too
+    fn receive(&mut self) -> Vec<ProtocolMessage> {
+        self.flush();
+
+        for message in self.inner.receive() {
+            if let Some(due) = self.schedule() {
+                self.incoming.push(Delayed { due, message });
+            }
+        }
+        take_due(&mut self.incoming).into_iter().map(|delayed| delayed.message).collect()
+    }
+
+    fn status(&self) -> ConnectionStatus {
+        self.inner.status()
+    }
+
+    fn take_reconnected(&mut self) -> bool {
+        self.inner.take_reconnected()
+    }
+}
@@ -0,0 +1,69 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{ConnectionStatus, NetworkBackend, ProtocolMessage};
+use crate::leaderboard::{Leaderboard, LeaderboardEntry};
+
+
+#[derive(Default)]
+/// In-process stand-in for the server, used for the single player game
+pub struct LocalBackend {
+    replies : Vec<ProtocolMessage>,
+    /// Games finished since the start, the single player leaderboard isn't saved
+    leaderboard : Leaderboard,
+}
+
+
+impl NetworkBackend for LocalBackend {
+
+    /// Answer the requests the same way an empty server would
+    fn send(&mut self, msg : &ProtocolMessage) {
+        match msg {
+            ProtocolMessage::IsName { name, .. } => {
+                self.replies.push(ProtocolMessage::IsName { name : name.clone(), accepted : true });
+            }
+            // the accounts are kept only by the server
+            ProtocolMessage::Login { name, register, .. } => {
+                let error = Some("The accounts need the game server".to_string());
+                self.replies.push(ProtocolMessage::Login { name : name.clone(), password : None, token : None, register : *register, error });
+            }
+            // the game keeps its own seed
+            ProtocolMessage::Seed { seed } => {
+                self.replies.push(ProtocolMessage::Seed { seed : *seed });
+            }
+            ProtocolMessage::Died { name, depth, max_depth, kills, turns, cause } => {
+                self.leaderboard.entries.push(LeaderboardEntry {
+                    name : name.clone(),
+                    room : String::new(),
+                    max_depth : *max_depth.max(depth),
+                    kills : *kills,
+                    turns : *turns,
+                    cause : cause.clone(),
+                    date : SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default(),
+                });
+            }
+            // the single player games are played in no room
+            ProtocolMessage::Rating { column, descending, page, all_rooms, .. } => {
+                let (entries, total) = self.leaderboard.page(None, *column, *descending, *page);
+                self.replies.push(ProtocolMessage::Rating {
+                    column : *column,
+                    descending : *descending,
+                    page : *page,
+                    room : None,
+                    all_rooms : *all_rooms,
+                    entries,
+                    total,
+                });
+            }
+            // there is nobody else to tell about it
+            _ => {}
+        }
+    }
+
+    fn receive(&mut self) -> Vec<ProtocolMessage> {
+        std::mem::take(&mut self.replies)
+    }
+
+    fn status(&self) -> ConnectionStatus {
+        ConnectionStatus::Offline
+    }
+}
@@ -0,0 +1,7 @@
+// Submodule input file
+
+pub mod local_backend;
+pub mod websocket_backend;
+pub mod recording_backend;
+pub mod scripted_backend;
+pub mod lagging_backend;
@@ -0,0 +1,86 @@
+use std::fs::File;
+use std::io::Write;
+use std::time::Instant;
+
+use serde::{Serialize, Deserialize};
+
+use crate::{ConnectionStatus, NetworkBackend, ProtocolMessage};
+
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+/// Which side has sent the recorded message
+pub enum Direction {
+    Sent,
+    Received,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+/// One line of the recording, the scripted backend reads the same format
+pub struct RecordedMessage {
+    /// Milliseconds since the start of the recording, the handwritten scripts may leave it out
+    #[serde(default)]
+    pub at_ms : u64,
+    pub direction : Direction,
+    pub message : ProtocolMessage,
+}
+
+
+/// Writes every message that goes through the wrapped backend to the file, one json object per line
+pub struct RecordingBackend {
+    inner : Box<dyn NetworkBackend>,
+    file : File,
+    started : Instant,
+}
+
+
+impl RecordingBackend {
+
+    /// Start recording the traffic of the backend to the open file
+    pub fn new(inner : Box<dyn NetworkBackend>, file : File) -> Self {
+        RecordingBackend {
+            inner,
+            file,
+            started : Instant::now(),
+        }
+    }
+
+    /// Append the message to the file, the game goes on if the disk fails
+    fn write(&mut self, direction : Direction, message : &ProtocolMessage) {
+        let line = RecordedMessage {
+            at_ms : self.started.elapsed().as_millis() as u64,
+            direction,
+            message : message.clone(),
+        };
+        let data = serde_json::to_string(&line).expect("Can't serialize recorded message");
+        if let Err(e) = writeln!(self.file, "{}", data) {
+            println!("Can't record message: {}", e);
+        }
+    }
+}
+
+
+impl NetworkBackend for RecordingBackend {
+
+    fn send(&mut self, msg : &ProtocolMessage) {
+        self.write(Direction::Sent, msg);
+        self.inner.send(msg);
+    }
+
+    fn receive(&mut self) -> Vec<ProtocolMessage> {
+        let received = self.inner.receive();
+        for msg in received.iter() {
+            self.write(Direction::Received, msg);
+        }
+        received
+    }
+
+    fn status(&self) -> ConnectionStatus {
+        self.inner.status()
+    }
+
+    fn take_reconnected(&mut self) -> bool {
+        self.inner.take_reconnected()
+    }
+}
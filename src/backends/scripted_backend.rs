@@ -0,0 +1,114 @@
+use std::collections::VecDeque;
+use std::fs;
+use std::mem;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use crate::{ConnectionStatus, NetworkBackend, ProtocolMessage};
+use crate::backends::recording_backend::{Direction, RecordedMessage};
+
+
+#[derive(Default, Clone, Debug, PartialEq)]
+/// How far the game has followed the script, the tests check it after the game is done
+pub struct ScriptReport {
+    /// Lines of the script that haven't been played yet
+    pub remaining : usize,
+    /// Messages of the game that differ from the ones the script expects
+    pub unexpected : Vec<String>,
+}
+
+
+/// Fake server that plays back the recorded or handwritten conversation.
+/// The received messages of the script come only after the game has sent the messages that go before them,
+/// so the same script drives the game the same way on every run, no matter how fast the machine is
+pub struct ScriptedBackend {
+    script : VecDeque<RecordedMessage>,
+    /// The end of the script is reported only once
+    finished : bool,
+    report : Arc<Mutex<ScriptReport>>,
+}
+
+
+impl ScriptedBackend {
+
+    /// Read the script, one json object per line, the empty lines are skipped
+    pub fn load(path : &Path) -> Result<Self, String> {
+        let data = fs::read_to_string(path).map_err(|e| format!("Can't read script {}: {}", path.display(), e))?;
+        let script = data.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| serde_json::from_str::<RecordedMessage>(line)
+                .map_err(|e| format!("Can't parse line {} of script {}: {}", i + 1, path.display(), e)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ScriptedBackend::new(script))
+    }
+
+    /// Play back the given conversation
+    pub fn new(script : Vec<RecordedMessage>) -> Self {
+        let report = ScriptReport { remaining : script.len(), unexpected : Vec::new() };
+        ScriptedBackend {
+            script : script.into(),
+            finished : false,
+            report : Arc::new(Mutex::new(report)),
+        }
+    }
+
+    /// Report that is kept up to date while the game plays the script, it stays readable after the backend is gone
+    pub fn report(&self) -> Arc<Mutex<ScriptReport>> {
+        Arc::clone(&self.report)
+    }
+
+    /// Remember the message of the game the script doesn't expect
+    fn unexpected(&mut self, text : String) {
+        println!("{}", text);
+        self.report.lock().unwrap().unexpected.push(text);
+    }
+}
+
+
+impl NetworkBackend for ScriptedBackend {
+
+    /// The message is checked against the next one the script expects from the game.
+    /// The other messages of the game are reported and don't move the script on
+    fn send(&mut self, msg : &ProtocolMessage) {
+        let text = match self.script.front() {
+            Some(RecordedMessage { direction : Direction::Sent, message, .. }) if mem::discriminant(message) == mem::discriminant(msg) => {
+                let text = Some(format!("Script expects {}, the game sends {}", message.encode(), msg.encode()))
+                    .filter(|_| message != msg);
+                self.script.pop_front();
+                self.report.lock().unwrap().remaining = self.script.len();
+                text
+            }
+            Some(RecordedMessage { direction : Direction::Sent, message, .. }) => {
+                Some(format!("Script expects {}, the game sends {}, it is skipped", message.encode(), msg.encode()))
+            }
+            Some(_) => Some(format!("Script doesn't expect {} now, it is skipped", msg.encode())),
+            // the game goes on alone after the end of the script
+            None => None,
+        };
+        if let Some(text) = text {
+            self.unexpected(text);
+        }
+    }
+
+    /// All received messages up to the next one the game has to send
+    fn receive(&mut self) -> Vec<ProtocolMessage> {
+        let mut received = Vec::new();
+        while let Some(RecordedMessage { direction : Direction::Received, .. }) = self.script.front() {
+            if let Some(line) = self.script.pop_front() {
+                received.push(line.message);
+            }
+        }
+        self.report.lock().unwrap().remaining = self.script.len();
+
+        if self.script.is_empty() && !self.finished {
+            self.finished = true;
+            println!("Script is over");
+        }
+        received
+    }
+
+    fn status(&self) -> ConnectionStatus {
+        ConnectionStatus::Connected
+    }
+}
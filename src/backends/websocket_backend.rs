@@ -0,0 +1,207 @@
+use std::net::TcpStream;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, Sender, Receiver, RecvTimeoutError, TryRecvError};
+use std::thread;
+use std::time::Duration;
+use url::Url;
+
+use websocket::{ClientBuilder, OwnedMessage, WebSocketResult};
+use websocket::result::WebSocketOtherError;
+use websocket::sync::Client;
+
+use crate::{ConnectionStatus, NetworkBackend, ProtocolMessage, RECONNECT_MIN_DELAY_MS, RECONNECT_MAX_DELAY_MS};
+
+
+/// How often the writing loop checks that the connection is still alive
+const POLL_INTERVAL_MS : u64 = 100;
+
+
+/// State of the connection shared between the game and the network thread
+struct Connection {
+    status : ConnectionStatus,
+    reconnected : bool,
+}
+
+
+/// Talks to the game server over a WebSocket.
+/// The socket lives in background threads, so the game never waits for the network.
+/// Dropped connections are restored with backoff, the messages sent meanwhile are queued.
+/// Every connection is opened with the hello message
+pub struct WebSocketBackend {
+    outgoing : Sender<ProtocolMessage>,
+    incoming : Receiver<ProtocolMessage>,
+    connection : Arc<Mutex<Connection>>,
+}
+
+
+impl WebSocketBackend {
+
+    /// Connect to the server and start the network thread, the first connection must succeed.
+    /// Every connection is opened with the given hello
+    pub fn connect(request : &Url, hello : ProtocolMessage) -> WebSocketResult<Self> {
+        let client = open(request)?;
+
+        let (outgoing, outgoing_rx) = mpsc::channel::<ProtocolMessage>();
+        let (incoming_tx, incoming) = mpsc::channel::<ProtocolMessage>();
+        let connection = Arc::new(Mutex::new(Connection {
+            status : ConnectionStatus::Connected,
+            reconnected : false,
+        }));
+
+        let url = request.clone();
+        let shared = Arc::clone(&connection);
+        thread::spawn(move || {
+            let mut client = Some(client);
+            let mut attempt = 0;
+            let mut unsent = None;
+
+            loop {
+                let current = match client.take() {
+                    Some(current) => current,
+                    None => {
+                        attempt += 1;
+                        shared.lock().unwrap().status = ConnectionStatus::Reconnecting { attempt };
+                        thread::sleep(backoff(attempt));
+
+                        match open(&url) {
+                            Ok(current) => {
+                                println!("Reconnected to {}", url);
+                                let mut connection = shared.lock().unwrap();
+                                connection.status = ConnectionStatus::Connected;
+                                connection.reconnected = true;
+                                current
+                            }
+                            Err(e) => {
+                                println!("Can't reconnect to {}: {}", url, e);
+                                continue;
+                            }
+                        }
+                    }
+                };
+                attempt = 0;
+
+                match serve(current, &hello, &outgoing_rx, &incoming_tx, &mut unsent) {
+                    // the game has been closed
+                    None => return,
+                    Some(reason) => println!("Connection to {} is lost: {}", url, reason),
+                }
+            }
+        });
+
+        Ok(WebSocketBackend {
+            outgoing,
+            incoming,
+            connection,
+        })
+    }
+}
+
+
+/// Open the WebSocket connection
+fn open(request : &Url) -> WebSocketResult<Client<TcpStream>> {
+    ClientBuilder::new(request.as_str())
+        .map_err(WebSocketOtherError::from)?
+        .connect_insecure()
+}
+
+
+/// Delay before the given reconnect attempt, doubles every time
+fn backoff(attempt : u32) -> Duration {
+    let delay = RECONNECT_MIN_DELAY_MS.saturating_mul(1 << (attempt - 1).min(16));
+    Duration::from_millis(delay.min(RECONNECT_MAX_DELAY_MS))
+}
+
+
+/// Pass messages through one connection until it breaks.
+/// Returns the reason of the break or `None` if the game is gone
+fn serve(client : Client<TcpStream>,
+         hello : &ProtocolMessage,
+         outgoing : &Receiver<ProtocolMessage>,
+         incoming : &Sender<ProtocolMessage>,
+         unsent : &mut Option<ProtocolMessage>) -> Option<String> {
+    let (mut reader, mut writer) = match client.split() {
+        Ok(parts) => parts,
+        Err(e) => return Some(e.to_string()),
+    };
+    let (closed_tx, closed) = mpsc::channel::<()>();
+
+    // reading thread, decodes messages and passes them to the game
+    let incoming = incoming.clone();
+    thread::spawn(move || {
+        for msg in reader.incoming_messages() {
+            let data = match msg {
+                Ok(OwnedMessage::Text(data)) => data,
+                Ok(OwnedMessage::Binary(data)) => String::from_utf8_lossy(&data).into_owned(),
+                Ok(OwnedMessage::Close(_)) | Err(_) => break,
+                Ok(_) => continue,
+            };
+
+            match ProtocolMessage::decode(&data) {
+                Ok(message) => {
+                    if incoming.send(message).is_err() {
+                        break;
+                    }
+                }
+                Err(e) => println!("Can't decode message {}: {}", data, e),
+            }
+        }
+        closed_tx.send(()).ok();
+    });
+
+    // every connection starts with the handshake, the server ignores everything else before it
+    if let Err(e) = writer.send_message(&OwnedMessage::Text(hello.encode())) {
+        writer.shutdown_all().ok();
+        return Some(e.to_string());
+    }
+
+    let mut reason = None;
+    loop {
+        if closed.try_recv() != Err(TryRecvError::Empty) {
+            reason = Some("closed by the server".to_string());
+            break;
+        }
+
+        // the message that failed on the previous connection goes first
+        let msg = match unsent.take() {
+            Some(msg) => msg,
+            None => match outgoing.recv_timeout(Duration::from_millis(POLL_INTERVAL_MS)) {
+                Ok(msg) => msg,
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        };
+
+        if let Err(e) = writer.send_message(&OwnedMessage::Text(msg.encode())) {
+            *unsent = Some(msg);
+            reason = Some(e.to_string());
+            break;
+        }
+    }
+
+    // stops the reading thread too
+    writer.shutdown_all().ok();
+    reason
+}
+
+
+impl NetworkBackend for WebSocketBackend {
+
+    fn send(&mut self, msg : &ProtocolMessage) {
+        // the network thread lives as long as the backend, so the message is only lost if it has panicked
+        if self.outgoing.send(msg.clone()).is_err() {
+            println!("Can't send message, the network thread is stopped");
+        }
+    }
+
+    fn receive(&mut self) -> Vec<ProtocolMessage> {
+        self.incoming.try_iter().collect()
+    }
+
+    fn status(&self) -> ConnectionStatus {
+        self.connection.lock().unwrap().status
+    }
+
+    fn take_reconnected(&mut self) -> bool {
+        std::mem::take(&mut self.connection.lock().unwrap().reconnected)
+    }
+}
@@ -0,0 +1,953 @@
+// Game server: keeps the lobby of game rooms, hands out the seed of every room, keeps the leaderboard and the accounts on disk,
+// chooses the authority of every depth and broadcasts the game events to the players of the same room
+
+use std::collections::{BTreeMap, HashMap};
+use std::net::TcpStream;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use websocket::OwnedMessage;
+use websocket::sync::{Server, Writer};
+
+use multiplayer_roguelike::protocol::*;
+use multiplayer_roguelike::leaderboard::{Leaderboard, LeaderboardEntry};
+use multiplayer_roguelike::accounts::{Accounts, Checked, Credentials};
+use multiplayer_roguelike::map_codec::{self, MapEncoding};
+use multiplayer_roguelike::map::{level_seed, TileType};
+use multiplayer_roguelike::map_builders::{self, BuiltLevel};
+use multiplayer_roguelike::map_builders::prefabs::{self, Prefab};
+use multiplayer_roguelike::constants::{PLAYER_MAX_HP, MONSTER_MAX_HP};
+use multiplayer_roguelike::validation;
+use rltk::RandomNumberGenerator;
+
+
+/// Address the server listens on if nothing else is passed in the arguments
+const DEFAULT_ADDRESS : &str = "0.0.0.0:6881";
+
+/// File of the leaderboard if nothing else is passed in the arguments
+const DEFAULT_LEADERBOARD_FILE : &str = "leaderboard.json";
+
+/// File of the accounts if nothing else is passed in the arguments
+const DEFAULT_ACCOUNTS_FILE : &str = "accounts.json";
+
+
+/// Connection of one client
+struct Session {
+    writer : Writer<TcpStream>,
+    /// Set after the successful handshake, nothing else is accepted before it
+    greeted : bool,
+    capabilities : Vec<String>,
+    /// Room the player has joined in the lobby, the game messages go only to it
+    room : Option<String>,
+    /// Name of the player is known after the accepted `IsName`, `Login` or `TrackMe`, the depth after `TrackMe`
+    name : Option<String>,
+    depth : Option<i32>,
+    /// Tile of the last move of the player on the depth
+    idx : Option<i32>,
+    /// The client only watches the players of its room
+    spectator : bool,
+    /// Registered name the client has logged in to
+    account : Option<String>,
+}
+
+
+/// Game room, its players share the world and don't see other rooms
+struct Room {
+    max_players : u32,
+    mode : GameMode,
+    /// Seed of the world of the room
+    seed : u64,
+    /// Client that simulates the monsters of the depth
+    authorities : HashMap<i32, usize>,
+    /// Levels the moves are checked against, they are built from the seed when somebody comes to the depth
+    /// and dropped when the last player leaves it
+    levels : HashMap<i32, BuiltLevel>,
+    /// The last shared monsters and items of every depth, the player that comes back to the empty depth gets them
+    worlds : HashMap<i32, Vec<SharedEntity>>,
+    /// The last tiles the authorities have moved the shared monsters of every depth to
+    positions : HashMap<i32, HashMap<u64, i32>>,
+}
+
+
+#[derive(Default)]
+/// What the server has seen of the game of one player, the stats the game reports with its death are clamped to it.
+/// It is kept by the name, so it outlives the reconnects, and it is dropped with the death
+struct Run {
+    max_depth : i32,
+    turns : u32,
+    /// Monsters and players that have died on the depth of the player, it can't have killed more
+    deaths_seen : u32,
+}
+
+
+#[derive(Default)]
+/// Everything the server knows about the game
+struct ServerState {
+    names : Vec<String>,
+    runs : HashMap<String, Run>,
+    rooms : BTreeMap<String, Room>,
+    clients : HashMap<usize, Session>,
+    /// Finished games of all rooms, it is written to the file after every death
+    leaderboard : Leaderboard,
+    leaderboard_file : PathBuf,
+    /// Registered names, only their owners may take them
+    accounts : Accounts,
+    accounts_file : PathBuf,
+    /// Hand-made rooms and vaults, the games must have the same ones to build the same levels
+    prefabs : Vec<Prefab>,
+    prefab_hash : u64,
+}
+
+
+impl ServerState {
+
+    /// Send message to the given client
+    fn send(&mut self, id : usize, msg : &ProtocolMessage) {
+        if let Some(session) = self.clients.get_mut(&id) {
+            if let Err(e) = session.writer.send_message(&encode_for(&session.capabilities, msg)) {
+                println!("Can't send message to client {}: {}", id, e);
+            }
+        }
+    }
+
+    /// Send message to all clients of the room
+    fn broadcast(&mut self, room : &str, msg : &ProtocolMessage) {
+        for (id, session) in self.clients.iter_mut().filter(|(_, session)| session.greeted && session.room.as_deref() == Some(room)) {
+            if let Err(e) = session.writer.send_message(&encode_for(&session.capabilities, msg)) {
+                println!("Can't send message to client {}: {}", id, e);
+            }
+        }
+    }
+
+    /// Send the message to the clients of the room that have agreed on the capability
+    fn broadcast_capable(&mut self, room : &str, capability : &str, msg : &ProtocolMessage) {
+        let data = OwnedMessage::Text(msg.encode());
+        for (id, session) in self.clients.iter_mut()
+            .filter(|(_, session)| session.greeted && session.room.as_deref() == Some(room))
+            .filter(|(_, session)| session.capabilities.iter().any(|c| c == capability)) {
+            if let Err(e) = session.writer.send_message(&data) {
+                println!("Can't send message to client {}: {}", id, e);
+            }
+        }
+    }
+
+    /// Check the version and the prefabs of the client and agree on the capabilities.
+    /// Returns false if the client is rejected
+    fn greet(&mut self, id : usize, version : u32, capabilities : Vec<String>, prefabs : Option<u64>) -> bool {
+        let error = if version < MIN_PROTOCOL_VERSION {
+            Some(format!("Your game is too old: protocol {}, the server needs at least {}. Please update the game",
+                version, MIN_PROTOCOL_VERSION))
+        } else if prefabs.is_some_and(|hash| hash != self.prefab_hash) {
+            Some("Your game has other prefabs than the server, its levels would differ. Please update the prefabs".to_string())
+        } else {
+            None
+        };
+
+        let common = capabilities.into_iter()
+            .filter(|c| CAPABILITIES.contains(&c.as_str()))
+            .collect::<Vec<_>>();
+        if let Some(session) = self.clients.get_mut(&id) {
+            session.greeted = error.is_none();
+            session.capabilities = common.clone();
+        }
+
+        let accepted = error.is_none();
+        let version = version.min(PROTOCOL_VERSION);
+        self.send(id, &ProtocolMessage::Hello { version, capabilities : common, error, prefabs : Some(self.prefab_hash) });
+        accepted
+    }
+
+    /// Handle one message from the given client.
+    /// Returns false if the connection should be closed
+    fn handle(&mut self, id : usize, msg : ProtocolMessage) -> bool {
+        if let ProtocolMessage::Hello { version, capabilities, prefabs, .. } = msg {
+            return self.greet(id, version, capabilities, prefabs);
+        }
+
+        let (room, spectator) = match self.clients.get(&id) {
+            Some(session) if session.greeted => (session.room.clone(), session.spectator),
+            _ => {
+                let error = Some("The game has to send the hello message first. Please update the game".to_string());
+                self.send(id, &ProtocolMessage::Hello { version : PROTOCOL_VERSION, capabilities : Vec::new(), error, prefabs : None });
+                return false;
+            }
+        };
+
+        // the server keeps the entities of the levels plain and packs them again for every client
+        let msg = match map_codec::unpack_world(msg) {
+            Ok(msg) => msg,
+            Err(e) => {
+                println!("Can't unpack world of client {}: {}", id, e);
+                return true;
+            }
+        };
+
+        // the impossible moves and hits of a modified game go nowhere
+        if let Err(reason) = self.validate(id, room.as_deref(), &msg) {
+            println!("Client {} is refused: {}", id, reason);
+            self.send(id, &ProtocolMessage::Rejected { message : Box::new(msg), reason });
+            return true;
+        }
+
+        match msg {
+            // only the server chooses the authority, refuses the messages and tells who has left.
+            // The login is handled in parts around argon2, see `begin_login` and `finish_login`
+            ProtocolMessage::Hello { .. } | ProtocolMessage::Authority { .. } | ProtocolMessage::Left { .. }
+            | ProtocolMessage::Rejected { .. } | ProtocolMessage::Login { .. } => {}
+            // spectators only watch, they don't change the world of the room
+            ProtocolMessage::Move { .. } | ProtocolMessage::Damage { .. } | ProtocolMessage::Change { .. }
+            | ProtocolMessage::ItemPickup { .. } | ProtocolMessage::ItemDrop { .. } | ProtocolMessage::HitEntity { .. }
+            | ProtocolMessage::EntityConfusion { .. } | ProtocolMessage::HitPlayer { .. } | ProtocolMessage::Turn { .. }
+            | ProtocolMessage::Swap { .. } | ProtocolMessage::EntityMove { .. } | ProtocolMessage::EntityHp { .. }
+            | ProtocolMessage::World { .. } | ProtocolMessage::Died { .. } | ProtocolMessage::TrackMe { .. }
+            | ProtocolMessage::Chat { .. } if spectator => {}
+            // the spectator comes to the depth, the authority sends it the state of the level
+            ProtocolMessage::WorldRequest { depth } => {
+                let authority = room.as_ref()
+                    .filter(|_| spectator)
+                    .and_then(|room| self.rooms.get(room))
+                    .and_then(|room| room.authorities.get(&depth))
+                    .copied();
+                if let Some(authority) = authority {
+                    self.send(authority, &msg);
+                }
+            }
+            ProtocolMessage::Move { idx, .. } => {
+                // the spectators that start to watch the player get the last place
+                if let Some(session) = self.clients.get_mut(&id) {
+                    session.idx = Some(idx);
+                }
+                if let Some(room) = room {
+                    self.broadcast(&room, &msg);
+                }
+            }
+            ProtocolMessage::Damage { .. } | ProtocolMessage::Change { .. }
+            | ProtocolMessage::ItemPickup { .. } | ProtocolMessage::ItemDrop { .. } | ProtocolMessage::HitEntity { .. }
+            | ProtocolMessage::EntityConfusion { .. } | ProtocolMessage::HitPlayer { .. } | ProtocolMessage::Swap { .. } => {
+                if let Some(room) = room {
+                    self.broadcast(&room, &msg);
+                }
+            }
+            // every game counts its turns here, the authority moves the monsters on the turns of the others
+            ProtocolMessage::Turn { depth } => {
+                if let Some(name) = self.clients.get(&id).and_then(|session| session.name.clone()) {
+                    self.runs.entry(name).or_default().turns += 1;
+                }
+                if let Some(room) = room {
+                    if self.rooms.get(&room).and_then(|r| r.authorities.get(&depth)) != Some(&id) {
+                        self.broadcast(&room, &msg);
+                    }
+                }
+            }
+            // the games without the chat don't get its lines
+            ProtocolMessage::Chat { .. } => {
+                if let Some(room) = room {
+                    self.broadcast_capable(&room, CHAT, &msg);
+                }
+            }
+            // the monsters of the depth are moved only by its authority
+            ProtocolMessage::EntityMove { depth, .. } | ProtocolMessage::EntityHp { depth, .. } => {
+                if let Some(room) = room {
+                    if let Some(info) = self.rooms.get_mut(&room).filter(|r| r.authorities.get(&depth) == Some(&id)) {
+                        match msg {
+                            ProtocolMessage::EntityMove { id, idx, .. } => {
+                                info.positions.entry(depth).or_default().insert(id, idx);
+                            }
+                            ProtocolMessage::EntityHp { hp, .. } if hp < 1 => self.count_death(&room, depth),
+                            _ => {}
+                        }
+                        self.broadcast(&room, &msg);
+                    }
+                }
+            }
+            ProtocolMessage::World { depth, ref entities, .. } => {
+                if let Some(room) = room {
+                    if let Some(info) = self.rooms.get_mut(&room).filter(|r| r.authorities.get(&depth) == Some(&id)) {
+                        info.worlds.insert(depth, entities.clone());
+                        info.positions.insert(depth, entities.iter().map(|entity| (entity.id, entity.idx)).collect());
+                        self.broadcast(&room, &msg);
+                    }
+                }
+            }
+            ProtocolMessage::Died { ref name, depth, max_depth, kills, turns, ref cause } => {
+                // the game can't claim more than the server has seen of it
+                let run = self.runs.remove(name).unwrap_or_default();
+                self.leaderboard.entries.push(LeaderboardEntry {
+                    name : name.clone(),
+                    room : room.clone().unwrap_or_default(),
+                    max_depth : max_depth.max(depth).min(run.max_depth),
+                    kills : kills.min(run.deaths_seen),
+                    turns : turns.min(run.turns),
+                    cause : cause.clone(),
+                    date : SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default(),
+                });
+                if let Err(e) = self.leaderboard.save(&self.leaderboard_file) {
+                    println!("{}", e);
+                }
+
+                // the dead player is gone from the world, another one may take the name
+                let depth = self.clients.get_mut(&id).and_then(|session| {
+                    session.name = None;
+                    session.depth.take()
+                });
+                self.names.retain(|taken| taken != name);
+                if let Some(room) = room {
+                    if let Some(depth) = depth {
+                        self.elect(&room, depth);
+                        self.count_death(&room, depth);
+                    }
+                    self.drop_unused_levels(&room);
+                    self.broadcast(&room, &msg);
+                }
+            }
+            ProtocolMessage::IsName { name, .. } => {
+                let logged_in = self.clients.get(&id).is_some_and(|session| session.account.as_ref() == Some(&name));
+                let accepted = !self.names.contains(&name) && (logged_in || !self.accounts.is_registered(&name));
+                if accepted {
+                    self.take_name(id, &name);
+                }
+                self.send(id, &ProtocolMessage::IsName { name, accepted });
+            }
+            ProtocolMessage::Rooms { .. } => {
+                let rooms = self.rooms.iter()
+                    .map(|(name, room)| RoomInfo {
+                        name : name.clone(),
+                        players : self.players_in(name),
+                        max_players : room.max_players,
+                        mode : room.mode,
+                    })
+                    .collect();
+                self.send(id, &ProtocolMessage::Rooms { rooms });
+            }
+            ProtocolMessage::CreateRoom { room, max_players, mode, seed, .. } => {
+                let error = if room.is_empty() {
+                    Some("The room needs a name".to_string())
+                } else if self.rooms.contains_key(&room) {
+                    Some(format!("The room {} already exists", room))
+                } else {
+                    None
+                };
+
+                let seed = match error {
+                    Some(_) => None,
+                    None => {
+                        let seed = seed.unwrap_or_else(random_seed);
+                        self.create_room(&room, max_players, mode, seed);
+                        self.join_room(id, &room);
+                        Some(seed)
+                    }
+                };
+                self.send(id, &ProtocolMessage::CreateRoom { room, max_players, mode, seed, error });
+            }
+            ProtocolMessage::JoinRoom { room, spectator, seed, mode, max_players, .. } => {
+                // the room is restored for the players that come back after the restart of the server,
+                // only with all the settings it was created with
+                if let (false, Some(seed), Some(mode), Some(max_players)) = (self.rooms.contains_key(&room), seed, mode, max_players) {
+                    self.create_room(&room, max_players, mode, seed);
+                }
+
+                let error = match self.rooms.get(&room) {
+                    None => Some(format!("There is no room {}", room)),
+                    Some(_) if self.clients[&id].room.as_ref() == Some(&room) => None,
+                    Some(_) if spectator => None,
+                    Some(info) if self.players_in(&room) >= info.max_players => Some(format!("The room {} is full", room)),
+                    Some(_) => None,
+                };
+                if error.is_none() {
+                    self.join_room(id, &room);
+                    if let Some(session) = self.clients.get_mut(&id) {
+                        session.spectator = spectator;
+                    }
+                }
+                let mode = self.rooms.get(&room).map(|info| info.mode);
+                let max_players = self.rooms.get(&room).map(|info| info.max_players);
+                self.send(id, &ProtocolMessage::JoinRoom { room, spectator, seed : None, mode, max_players, error });
+            }
+            ProtocolMessage::Seed { seed } => {
+                let seed = match room.and_then(|room| self.rooms.get(&room)) {
+                    Some(room) => Some(room.seed),
+                    None => seed,
+                };
+                self.send(id, &ProtocolMessage::Seed { seed });
+            }
+            ProtocolMessage::TrackMe { name, depth } => {
+                // the client announces itself again after a reconnect, the name stays taken
+                if !self.names.contains(&name) {
+                    self.names.push(name.clone());
+                }
+                let run = self.runs.entry(name.clone()).or_default();
+                run.max_depth = run.max_depth.max(depth);
+
+                // the player comes down to the start of the new depth or up to its down stairs,
+                // the tile is unknown when the game comes back after the restart of the server
+                let coming_up = self.clients.get(&id).and_then(|session| session.depth) == Some(depth + 1);
+                let arrival = room.as_deref()
+                    .and_then(|room| self.level(room, depth))
+                    .map(|level| match level.map.find_tile(TileType::DownStairs) {
+                        Some(stairs) if coming_up => stairs as i32,
+                        _ => level.start_idx(),
+                    });
+                let previous = self.clients.get_mut(&id).and_then(|session| {
+                    session.name = Some(name.clone());
+                    let previous = session.depth.replace(depth);
+                    if previous.is_some_and(|previous| previous != depth) {
+                        session.idx = arrival;
+                    }
+                    previous
+                });
+                if let Some(room) = room {
+                    if let Some(previous) = previous.filter(|previous| *previous != depth) {
+                        self.elect(&room, previous);
+                    }
+                    self.join_depth(&room, id, depth);
+                    self.drop_unused_levels(&room);
+                }
+            }
+            // the player in the room sees its games, the one in the main menu asks for the room of the last game
+            ProtocolMessage::Rating { column, descending, page, room : asked, all_rooms, .. } => {
+                let room = room.or(asked);
+                let scope = match all_rooms {
+                    true => None,
+                    false => Some(room.as_deref().unwrap_or_default()),
+                };
+                let (entries, total) = self.leaderboard.page(scope, column, descending, page);
+                self.send(id, &ProtocolMessage::Rating { column, descending, page, room, all_rooms, entries, total });
+            }
+            ProtocolMessage::Players { .. } => {
+                let players = self.clients.values()
+                    .filter(|session| !session.spectator && room.is_some() && session.room == room)
+                    .filter_map(|session| Some(PlayerInfo {
+                        name : session.name.clone()?,
+                        depth : session.depth?,
+                        idx : session.idx,
+                    }))
+                    .collect();
+                self.send(id, &ProtocolMessage::Players { players });
+            }
+        }
+        true
+    }
+
+    /// Check the move or the hit that the game reports about itself
+    fn validate(&mut self, id : usize, room : Option<&str>, msg : &ProtocolMessage) -> Result<(), String> {
+        let session = match self.clients.get(&id) {
+            Some(session) if !session.spectator => session,
+            _ => return Ok(()),
+        };
+
+        match msg {
+            ProtocolMessage::Move { name, idx, depth, .. } => {
+                if session.name.as_ref() != Some(name) {
+                    return Err(format!("The game can't move the player {}", name));
+                }
+                if session.depth != Some(*depth) {
+                    return Err(format!("The player {} isn't on the depth {}", name, depth));
+                }
+                let from = session.idx;
+                match room.and_then(|room| self.level(room, *depth)) {
+                    Some(level) => validation::check_move(&level.map, from, *idx),
+                    None => Ok(()),
+                }
+            }
+            ProtocolMessage::Damage { name, hp } => {
+                if session.name.as_ref() != Some(name) {
+                    return Err(format!("The game can't change the hp of the player {}", name));
+                }
+                validation::check_hp(*hp, PLAYER_MAX_HP)
+            }
+            // the game comes back after a reconnect, the registered name needs the login first.
+            // The stairs lead only one depth up or down, the depth is unknown only after the reconnect
+            ProtocolMessage::TrackMe { name, depth } => {
+                if *depth < 1 || session.depth.is_some_and(|current| (current - depth).abs() > 1) {
+                    return Err(format!("The player {} can't get to the depth {}", name, depth));
+                }
+                self.check_name(id, name)?;
+                if self.accounts.is_registered(name) && session.account.as_ref() != Some(name) {
+                    return Err(format!("The name {} is registered, the game has to log in first", name));
+                }
+                Ok(())
+            }
+            ProtocolMessage::Died { name, .. } => {
+                if session.name.as_ref() != Some(name) {
+                    return Err(format!("The game doesn't play as {}", name));
+                }
+                Ok(())
+            }
+            ProtocolMessage::Chat { name, .. } => {
+                if !session.capabilities.iter().any(|c| c == CHAT) {
+                    return Err("The game hasn't agreed on the chat in the handshake".to_string());
+                }
+                if session.name.as_ref() != Some(name) {
+                    return Err(format!("The game doesn't play as {}", name));
+                }
+                Ok(())
+            }
+            // the item falls on the tile of the player that drops it
+            ProtocolMessage::ItemDrop { name, idx, depth, .. } => {
+                if session.name.as_ref() != Some(name) {
+                    return Err(format!("The game can't drop the items of the player {}", name));
+                }
+                if session.depth != Some(*depth) {
+                    return Err(format!("The player {} isn't on the depth {}", name, depth));
+                }
+                let from = session.idx;
+                match room.and_then(|room| self.level(room, *depth)) {
+                    Some(level) => validation::check_move(&level.map, from, *idx),
+                    None => Ok(()),
+                }
+            }
+            // the ally of the co-op room takes the tile the player has just left, next to both of them
+            ProtocolMessage::Swap { name, idx, depth } => {
+                if session.depth != Some(*depth) {
+                    return Err(format!("The game isn't on the depth {}", depth));
+                }
+                let from = session.idx;
+                let ally = self.clients.values()
+                    .find(|other| other.room.as_deref() == room && other.name.as_ref() == Some(name) && other.depth == Some(*depth));
+                let ally_idx = match ally {
+                    Some(ally) => ally.idx,
+                    None => return Err(format!("The player {} isn't on the depth {}", name, depth)),
+                };
+                if room.and_then(|room| self.rooms.get(room)).is_none_or(|info| info.mode != GameMode::Coop) {
+                    return Err("Only the players of the co-op room swap places".to_string());
+                }
+                match room.and_then(|room| self.level(room, *depth)) {
+                    Some(level) => validation::check_move(&level.map, from, *idx)
+                        .and_then(|_| validation::check_move(&level.map, ally_idx, *idx)),
+                    None => Ok(()),
+                }
+            }
+            ProtocolMessage::EntityHp { hp, .. } => validation::check_hp(*hp, MONSTER_MAX_HP),
+            // the monster walks one step from the tile it has been moved to before
+            ProtocolMessage::EntityMove { depth, id : entity, idx } => {
+                let from = room.and_then(|room| self.rooms.get(room))
+                    .and_then(|info| info.positions.get(depth))
+                    .and_then(|positions| positions.get(entity))
+                    .copied();
+                match room.and_then(|room| self.level(room, *depth)) {
+                    Some(level) => validation::check_move(&level.map, from, *idx),
+                    None => Ok(()),
+                }
+            }
+            // the player hits and confuses the monsters of the own depth the spells reach
+            ProtocolMessage::HitEntity { depth, id : entity, .. } | ProtocolMessage::EntityConfusion { depth, id : entity, .. } => {
+                if session.depth != Some(*depth) {
+                    return Err(format!("The game isn't on the depth {}", depth));
+                }
+                let target = room.and_then(|room| self.entity_idx(room, *depth, *entity));
+                validation::check_reach(session.idx, target)?;
+                match msg {
+                    ProtocolMessage::HitEntity { amount, .. } => validation::check_hit(*amount),
+                    _ => Ok(()),
+                }
+            }
+            // the players of the depth hit each other only in the PvP room, the monsters are run by the authority of the depth
+            ProtocolMessage::HitPlayer { depth, name, amount, attacker, attacker_kind } => {
+                if session.depth != Some(*depth) {
+                    return Err(format!("The game isn't on the depth {}", depth));
+                }
+                let target = self.clients.values()
+                    .find(|other| other.room.as_deref() == room && other.name.as_ref() == Some(name) && other.depth == Some(*depth));
+                let target_idx = match target {
+                    Some(target) => target.idx,
+                    None => return Err(format!("The player {} isn't on the depth {}", name, depth)),
+                };
+                let info = room.and_then(|room| self.rooms.get(room));
+                match attacker_kind {
+                    AttackerKind::Player => {
+                        if session.name.as_ref() != Some(attacker) {
+                            return Err(format!("The game doesn't play as {}", attacker));
+                        }
+                        if info.is_some_and(|info| info.mode == GameMode::Coop) {
+                            return Err("The players of the co-op room can't hurt each other".to_string());
+                        }
+                        validation::check_reach(session.idx, target_idx)?;
+                    }
+                    AttackerKind::Monster => {
+                        if info.is_some_and(|info| info.authorities.get(depth) != Some(&id)) {
+                            return Err(format!("Only the authority of the depth {} runs its monsters", depth));
+                        }
+                    }
+                }
+                validation::check_hit(*amount)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Somebody has died on the depth, every player there may have dealt the killing blow
+    fn count_death(&mut self, room : &str, depth : i32) {
+        let names = self.clients.values()
+            .filter(|session| !session.spectator && session.room.as_deref() == Some(room) && session.depth == Some(depth))
+            .filter_map(|session| session.name.clone())
+            .collect::<Vec<_>>();
+        for name in names {
+            self.runs.entry(name).or_default().deaths_seen += 1;
+        }
+    }
+
+    /// The last known tile of the shared monster, the monster that hasn't moved yet stands on the tile
+    /// the level has spawned it on, that tile is a part of its id
+    fn entity_idx(&self, room : &str, depth : i32, id : u64) -> Option<i32> {
+        let known = self.rooms.get(room)
+            .and_then(|info| info.positions.get(&depth))
+            .and_then(|positions| positions.get(&id));
+        match known {
+            Some(idx) => Some(*idx),
+            None if (id >> 32) as i32 == depth => Some((id & 0xffff_ffff) as i32),
+            None => None,
+        }
+    }
+
+    /// Check that the game announces the name it plays as, not the name of another player
+    fn check_name(&self, id : usize, name : &str) -> Result<(), String> {
+        let own = self.clients.get(&id).and_then(|session| session.name.as_deref());
+        if own.is_some_and(|own| own != name) {
+            return Err(format!("The game doesn't play as {}", name));
+        }
+        let held = self.clients.iter()
+            .any(|(other, session)| *other != id && session.name.as_deref() == Some(name));
+        if held {
+            return Err(format!("The name {} is used right now", name));
+        }
+        Ok(())
+    }
+
+    /// Take the name for the client, the name taken before is freed
+    fn take_name(&mut self, id : usize, name : &str) {
+        self.names.push(name.to_string());
+
+        // the name is freed when the client leaves, the one taken before is freed now
+        let previous = self.clients.get_mut(&id).and_then(|session| session.name.replace(name.to_string()));
+        if let Some(previous) = previous.filter(|previous| previous != name) {
+            self.names.retain(|taken| *taken != previous);
+        }
+    }
+
+    /// Check if the client has sent the hello message
+    fn is_greeted(&self, id : usize) -> bool {
+        self.clients.get(&id).is_some_and(|session| session.greeted)
+    }
+
+    /// Check the name of the login, the password is left for `Credentials::check` outside the lock of the state
+    fn begin_login(&self, id : usize, name : &str, password : Option<&str>, token : Option<&str>, register : bool) -> Result<Credentials, String> {
+        self.check_free_name(id, name)?;
+        match register {
+            true => self.accounts.begin_register(name, password.unwrap_or_default()),
+            false => self.accounts.begin_login(name, password, token),
+        }
+    }
+
+    /// Take the checked login and answer it
+    fn finish_login(&mut self, id : usize, name : String, register : bool, checked : Result<Checked, String>) {
+        let reply = match checked.and_then(|checked| self.login(id, &name, register, checked)) {
+            Ok(token) => ProtocolMessage::Login { name, password : None, token : Some(token), register, error : None },
+            Err(error) => ProtocolMessage::Login { name, password : None, token : None, register, error : Some(error) },
+        };
+        self.send(id, &reply);
+    }
+
+    /// The name may be used by another client right now, the own name can be logged in to
+    fn check_free_name(&self, id : usize, name : &str) -> Result<(), String> {
+        let taken_here = self.clients.get(&id).is_some_and(|session| session.name.as_deref() == Some(name));
+        if self.names.iter().any(|taken| taken == name) && !taken_here {
+            return Err(format!("The name {} is used right now", name));
+        }
+        Ok(())
+    }
+
+    /// Register or log in to the checked account and take its name for the client.
+    /// Returns the token the game keeps for the next login
+    fn login(&mut self, id : usize, name : &str, register : bool, checked : Checked) -> Result<String, String> {
+        // the client may have left or another one may have taken the name while the password was checked
+        if !self.clients.contains_key(&id) {
+            return Err("The client has left".to_string());
+        }
+        self.check_free_name(id, name)?;
+
+        // the new account and the new token of the password login have to survive the restart
+        let changed = !matches!(checked, Checked::Token(_));
+        let token = self.accounts.commit(name, checked)?;
+        if changed {
+            if let Err(e) = self.accounts.save(&self.accounts_file) {
+                println!("{}", e);
+            }
+        }
+        if register {
+            println!("Client {} has registered the name {}", id, name);
+        }
+
+        let taken_here = self.clients.get(&id).is_some_and(|session| session.name.as_deref() == Some(name));
+        if let Some(session) = self.clients.get_mut(&id) {
+            session.account = Some(name.to_string());
+        }
+        if !taken_here {
+            self.take_name(id, name);
+        }
+        Ok(token)
+    }
+
+    /// Level of the room on the given depth, it is built from the seed of the room the first time
+    fn level(&mut self, room : &str, depth : i32) -> Option<&BuiltLevel> {
+        let room = self.rooms.get_mut(room)?;
+        let seed = room.seed;
+        let prefabs = &self.prefabs;
+        Some(room.levels.entry(depth).or_insert_with(|| {
+            let mut rng = RandomNumberGenerator::seeded(level_seed(seed, depth));
+            map_builders::build_level(depth, &mut rng, prefabs)
+        }))
+    }
+
+    /// Forget the levels of the room nobody is on, they are built again when somebody comes back
+    fn drop_unused_levels(&mut self, room : &str) {
+        let depths = self.clients.values()
+            .filter(|session| session.room.as_deref() == Some(room))
+            .filter_map(|session| session.depth)
+            .collect::<Vec<_>>();
+        if let Some(info) = self.rooms.get_mut(room) {
+            info.levels.retain(|depth, _| depths.contains(depth));
+        }
+    }
+
+    /// Number of the players in the room, the spectators are not counted
+    fn players_in(&self, room : &str) -> u32 {
+        self.clients.values()
+            .filter(|session| !session.spectator && session.room.as_deref() == Some(room))
+            .count() as u32
+    }
+
+    /// Open a new room
+    fn create_room(&mut self, name : &str, max_players : u32, mode : GameMode, seed : u64) {
+        println!("Room {} is created", name);
+        self.rooms.insert(name.to_string(), Room {
+            max_players : max_players.clamp(1, MAX_ROOM_PLAYERS),
+            mode,
+            seed,
+            authorities : HashMap::new(),
+            levels : HashMap::new(),
+            worlds : HashMap::new(),
+            positions : HashMap::new(),
+        });
+    }
+
+    /// Move the client to the room, the game starts there from the first depth
+    fn join_room(&mut self, id : usize, room : &str) {
+        if self.clients.get(&id).and_then(|session| session.room.as_deref()) == Some(room) {
+            return;
+        }
+        self.leave_room(id);
+
+        if let Some(session) = self.clients.get_mut(&id) {
+            session.room = Some(room.to_string());
+        }
+    }
+
+    /// Take the client out of its room, the empty room is closed
+    fn leave_room(&mut self, id : usize) {
+        let (room, depth, name) = match self.clients.get_mut(&id) {
+            Some(session) => {
+                session.spectator = false;
+                session.idx = None;
+                (session.room.take(), session.depth.take(), session.name.clone())
+            }
+            None => (None, None, None),
+        };
+        let room = match room {
+            Some(room) => room,
+            None => return,
+        };
+
+        // the level the last player has dropped out of is known for sure only by that player's game
+        if let Some(depth) = depth {
+            if self.elect(&room, depth).is_none() {
+                if let Some(info) = self.rooms.get_mut(&room) {
+                    info.worlds.remove(&depth);
+                    info.positions.remove(&depth);
+                }
+            }
+        }
+        self.drop_unused_levels(&room);
+        if let Some(name) = name {
+            self.broadcast(&room, &ProtocolMessage::Left { name });
+        }
+        // the room stays open while somebody watches it
+        if !self.clients.values().any(|session| session.room.as_deref() == Some(room.as_str())) {
+            println!("Room {} is closed", room);
+            self.rooms.remove(&room);
+        }
+    }
+
+    /// Choose the authority of the depth if it has none or it has left the depth.
+    /// Returns the authority, everybody in the room is told about the new one
+    fn elect(&mut self, room : &str, depth : i32) -> Option<usize> {
+        let current = self.rooms.get(room)?.authorities.get(&depth)
+            .filter(|id| {
+                self.clients.get(id)
+                    .is_some_and(|session| session.room.as_deref() == Some(room) && session.depth == Some(depth))
+            })
+            .copied();
+        if current.is_some() {
+            return current;
+        }
+
+        let elected = self.clients.iter()
+            .filter(|(_, session)| session.greeted && session.room.as_deref() == Some(room) && session.depth == Some(depth))
+            .map(|(id, _)| *id)
+            .min();
+        let authorities = &mut self.rooms.get_mut(room)?.authorities;
+        match elected {
+            Some(elected) => {
+                authorities.insert(depth, elected);
+                let name = self.clients[&elected].name.clone().unwrap_or_default();
+                self.broadcast(room, &ProtocolMessage::Authority { depth, name });
+            }
+            None => {
+                authorities.remove(&depth);
+            }
+        }
+        elected
+    }
+
+    /// The client has come to the depth: tell it the authority and ask the authority for the current state
+    /// of the level, the client that becomes the authority gets the state the level was left in
+    fn join_depth(&mut self, room : &str, id : usize, depth : i32) {
+        let previous = self.rooms.get(room).and_then(|r| r.authorities.get(&depth)).copied();
+        let authority = match self.elect(room, depth) {
+            Some(authority) => authority,
+            None => return,
+        };
+
+        // a new authority has already been announced to everybody
+        if previous == Some(authority) {
+            let name = self.clients[&authority].name.clone().unwrap_or_default();
+            self.send(id, &ProtocolMessage::Authority { depth, name });
+        }
+        if authority != id {
+            self.send(authority, &ProtocolMessage::WorldRequest { depth });
+            return;
+        }
+
+        // nobody was on the depth, the newcomer gets it as the last player has left it.
+        // From now on the level lives in the game of the newcomer
+        if previous != Some(id) {
+            let stored = self.rooms.get_mut(room).and_then(|r| r.worlds.remove(&depth));
+            if let Some(entities) = stored {
+                self.send(id, &ProtocolMessage::World { depth, entities, stored : true, packed : None });
+            }
+        }
+    }
+
+    /// Forget the client and free its name
+    fn disconnect(&mut self, id : usize) {
+        self.leave_room(id);
+
+        let name = self.clients.remove(&id).and_then(|session| session.name);
+        if let Some(name) = name {
+            self.names.retain(|taken| *taken != name);
+        }
+        if self.clients.is_empty() {
+            self.names.clear();
+        }
+    }
+}
+
+
+/// The message as the client reads it, the level goes in the most compact encoding the client knows
+fn encode_for(capabilities : &[String], msg : &ProtocolMessage) -> OwnedMessage {
+    match msg {
+        ProtocolMessage::World { .. } => OwnedMessage::Text(map_codec::pack_world(msg.clone(), MapEncoding::negotiate(capabilities)).encode()),
+        _ => OwnedMessage::Text(msg.encode()),
+    }
+}
+
+
+/// Seed for a new world
+fn random_seed() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or_default()
+}
+
+
+fn main() {
+    let address = std::env::args().nth(1).unwrap_or_else(|| DEFAULT_ADDRESS.to_string());
+    let leaderboard_file = PathBuf::from(std::env::args().nth(2).unwrap_or_else(|| DEFAULT_LEADERBOARD_FILE.to_string()));
+    let accounts_file = PathBuf::from(std::env::args().nth(3).unwrap_or_else(|| DEFAULT_ACCOUNTS_FILE.to_string()));
+    let prefab_dir = PathBuf::from(std::env::args().nth(4).unwrap_or_else(|| prefabs::PREFAB_DIR.to_string()));
+    let leaderboard = Leaderboard::load(&leaderboard_file).expect("Can't load leaderboard");
+    let accounts = Accounts::load(&accounts_file).expect("Can't load accounts");
+    let prefabs = prefabs::load_prefabs(&prefab_dir).expect("Can't load prefabs");
+    let prefab_hash = prefabs::prefab_hash(&prefabs);
+    let server = Server::bind(&address).expect("Can't bind server address");
+    println!("Server is listening on {}, the leaderboard is in {}, the accounts are in {}, {} prefabs are read from {}",
+        address, leaderboard_file.display(), accounts_file.display(), prefabs.len(), prefab_dir.display());
+
+    let state = Arc::new(Mutex::new(ServerState { leaderboard, leaderboard_file, accounts, accounts_file, prefabs, prefab_hash, ..ServerState::default() }));
+
+    for (id, request) in server.filter_map(Result::ok).enumerate() {
+        let state = Arc::clone(&state);
+
+        thread::spawn(move || {
+            let client = match request.accept() {
+                Ok(client) => client,
+                Err((_, e)) => {
+                    println!("Can't accept connection: {}", e);
+                    return;
+                }
+            };
+
+            let (mut reader, writer) = client.split().expect("Can't split connection");
+            {
+                let mut state = state.lock().unwrap();
+                state.clients.insert(id, Session {
+                    writer,
+                    greeted : false,
+                    capabilities : Vec::new(),
+                    room : None,
+                    name : None,
+                    depth : None,
+                    idx : None,
+                    spectator : false,
+                    account : None,
+                });
+                println!("Connection opened. There are now {} open connections.", state.clients.len());
+            }
+
+            for msg in reader.incoming_messages() {
+                let data = match msg {
+                    Ok(OwnedMessage::Text(data)) => data,
+                    Ok(OwnedMessage::Binary(data)) => String::from_utf8_lossy(&data).into_owned(),
+                    Ok(OwnedMessage::Ping(data)) => {
+                        let mut state = state.lock().unwrap();
+                        if let Some(session) = state.clients.get_mut(&id) {
+                            session.writer.send_message(&OwnedMessage::Pong(data)).ok();
+                        }
+                        continue;
+                    }
+                    Ok(OwnedMessage::Close(_)) | Err(_) => break,
+                    Ok(_) => continue,
+                };
+
+                match ProtocolMessage::decode(&data) {
+                    // argon2 takes its time, the state is free for the other clients while the password is hashed or checked
+                    Ok(ProtocolMessage::Login { name, password, token, register, .. }) if state.lock().unwrap().is_greeted(id) => {
+                        let credentials = state.lock().unwrap().begin_login(id, &name, password.as_deref(), token.as_deref(), register);
+                        let checked = credentials.and_then(Credentials::check);
+                        state.lock().unwrap().finish_login(id, name, register, checked);
+                    }
+                    Ok(message) => {
+                        if !state.lock().unwrap().handle(id, message) {
+                            println!("Client {} is rejected", id);
+                            break;
+                        }
+                    }
+                    Err(e) => println!("Can't decode message {}: {}", data, e),
+                }
+            }
+
+            let mut state = state.lock().unwrap();
+            state.disconnect(id);
+            println!("Connection closed. There are now {} open connections.", state.clients.len());
+        });
+    }
+}
@@ -1,92 +1,210 @@
-use std::net::TcpStream;
+use std::fs::File;
+use std::path::Path;
+use std::time::{Duration, Instant};
 use url::Url;
 
-use websocket::{ClientBuilder, Message, sync::Client};
+use websocket::WebSocketResult;
+
+use crate::{ProtocolMessage, REPLY_TIMEOUT_MS, MIN_PROTOCOL_VERSION};
+use crate::map_codec::{self, MapEncoding};
+use crate::backends::local_backend::LocalBackend;
+use crate::backends::websocket_backend::WebSocketBackend;
+use crate::backends::recording_backend::RecordingBackend;
+use crate::backends::scripted_backend::ScriptedBackend;
+use crate::backends::lagging_backend::{LaggingBackend, NetworkConditions};
+
+
+/// Transport that delivers messages to the server and back
+pub trait NetworkBackend {
+
+    /// Queue the message for the server
+    fn send(&mut self, msg : &ProtocolMessage);
+
+    /// Return all messages that have arrived since the last call, never blocks
+    fn receive(&mut self) -> Vec<ProtocolMessage>;
+
+    /// Current state of the connection
+    fn status(&self) -> ConnectionStatus;
+
+    /// Returns true once after the lost connection has been restored
+    fn take_reconnected(&mut self) -> bool {
+        false
+    }
+}
+
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+/// State of the connection to the server, is shown in the HUD
+pub enum ConnectionStatus {
+    Offline,
+    Connected,
+    Reconnecting { attempt : u32 },
+}
 
 
 /// Used to send / receive data from the server
 pub struct ClientHandler {
-    socket : Client<TcpStream>,
-    pub messages : Vec<(String, String)>,
+    backend : Box<dyn NetworkBackend>,
+    pub messages : Vec<ProtocolMessage>,
+    /// Capabilities both sides have agreed on in the handshake
+    pub capabilities : Vec<String>,
+    /// Reason why the server has refused to play with this client
+    pub rejection : Option<String>,
 }
 
 
 impl ClientHandler {
 
-    /// Create new ClientHandler and set timeout for reading messages in stream
-    pub fn new(request : Url) -> Self {
-        let client = ClientBuilder::new(request.as_str())
-		.unwrap()
-		// .add_protocol("rust-websocket")
-		.connect_insecure()
-		.unwrap();
-
-        // set timeout
-        client.stream_ref().set_read_timeout(Some(std::time::Duration::from_millis(15))).unwrap();
-
+    /// Create new ClientHandler on top of the given backend
+    pub fn new(backend : Box<dyn NetworkBackend>) -> Self {
         ClientHandler {
-            socket : client,
-            messages : Vec::<(String, String)>::new(),
+            backend,
+            messages : Vec::<ProtocolMessage>::new(),
+            capabilities : Vec::new(),
+            rejection : None,
         }
     }
 
-    /// Сollects all messages from the stream
+    /// Create ClientHandler for the single player game, no server is needed
+    pub fn local() -> Self {
+        ClientHandler::new(Box::new(LocalBackend::default()))
+    }
+
+    /// Connect to the game server
+    pub fn connect(request : &Url, hello : ProtocolMessage) -> WebSocketResult<Self> {
+        Ok(ClientHandler::new(Box::new(WebSocketBackend::connect(request, hello)?)))
+    }
+
+    /// Play back the script instead of talking to the server
+    pub fn scripted(path : &Path) -> Result<Self, String> {
+        Ok(ClientHandler::new(Box::new(ScriptedBackend::load(path)?)))
+    }
+
+    /// Pass all messages through the simulated bad network from now on
+    pub fn simulate(&mut self, conditions : NetworkConditions) {
+        let inner = std::mem::replace(&mut self.backend, Box::new(LocalBackend::default()));
+        self.backend = Box::new(LaggingBackend::new(inner, conditions));
+    }
+
+    /// Write all sent and received messages to the file from now on, the file is overwritten
+    pub fn record(&mut self, path : &Path) -> Result<(), String> {
+        let file = File::create(path).map_err(|e| format!("Can't record to {}: {}", path.display(), e))?;
+        let inner = std::mem::replace(&mut self.backend, Box::new(LocalBackend::default()));
+        self.backend = Box::new(RecordingBackend::new(inner, file));
+        Ok(())
+    }
+
+    /// Сollects all messages that have arrived since the last call
     pub fn get_messages(&mut self) {
+        let received = self.backend.receive().into_iter()
+            .filter_map(|msg| match map_codec::unpack_world(msg) {
+                Ok(msg) => Some(msg),
+                Err(e) => {
+                    println!("Can't unpack world: {}", e);
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
 
-        for msg in self.socket.incoming_messages() {
-            match msg {
-                Ok(m) => {
-                    match m {
-                        websocket::OwnedMessage::Text(data) => {
-
-                            // deserialize json
-                            let mut key = String::new();
-                            let mut turn = false;
-                            let mut skip = false;
-                            let mut skip_x2 = false;
-                            let mut count = 0;
-                            let mut value = String::new();
-
-                            for (i, ch) in data.chars().enumerate() {
-                                if i == 0 || i == 1 || i == data.len() - 1 || i == data.len() - 2 {
-                                    continue;   
-                                }
-                                if skip {
-                                    skip = false;
-                                    continue;
-                                }
-                                if skip_x2 {
-                                    if count == 1 {
-                                        skip_x2 = false;
-                                    }
-                                    count += 1;
-                                    continue;
-                                }
-                                if ch == '"' && !turn {
-                                    turn = true;
-                                    skip_x2 = true;
-                                } else if !turn {
-                                    key.push(ch);
-                                } else if ch == '\\' && turn {
-                                    continue;
-                                } else if turn {
-                                    value.push(ch);
-                                }
-                            }
-                            println!("message: {} {}", key, value);
-                            self.messages.push((key, value));
-                        }
-                        _ => (),
-                    }
+        // the handshake is repeated after every reconnect, so the result is tracked here
+        for msg in received.iter() {
+            if let ProtocolMessage::Hello { version, capabilities, error, .. } = msg {
+                if let Some(error) = error {
+                    self.rejection = Some(error.clone());
+                } else if *version < MIN_PROTOCOL_VERSION {
+                    self.rejection = Some(format!("The server is too old: protocol {}, the game needs at least {}",
+                        version, MIN_PROTOCOL_VERSION));
+                } else {
+                    self.capabilities = capabilities.clone();
                 }
-                Err(_) => break,
             }
         }
+
+        self.messages.extend(received);
+    }
+
+    /// Handle to wait for the server's answer to the hello message the backend has sent on connect
+    pub fn handshake(hello : ProtocolMessage) -> PendingRequest {
+        PendingRequest {
+            request : hello,
+            sent_at : Instant::now(),
+        }
+    }
+
+    /// Whether both sides have agreed on the capability in the handshake
+    pub fn has_capability(&self, capability : &str) -> bool {
+        self.capabilities.iter().any(|c| c == capability)
+    }
+
+    /// Current state of the connection
+    pub fn status(&self) -> ConnectionStatus {
+        self.backend.status()
+    }
+
+    /// Returns true once after the lost connection has been restored,
+    /// the game should announce itself to the server again
+    pub fn take_reconnected(&mut self) -> bool {
+        self.backend.take_reconnected()
+    }
+
+    /// Encoding of the levels sent to the server
+    pub fn map_encoding(&self) -> MapEncoding {
+        MapEncoding::negotiate(&self.capabilities)
+    }
+
+    /// Send messages to the server, the level goes in the most compact encoding both sides know
+    pub fn send_message(&mut self, msg : &ProtocolMessage) {
+        match msg {
+            ProtocolMessage::World { .. } => self.backend.send(&map_codec::pack_world(msg.clone(), self.map_encoding())),
+            _ => self.backend.send(msg),
+        }
+    }
+
+    /// Send a request and return a handle to wait for the reply
+    pub fn request(&mut self, msg : ProtocolMessage) -> PendingRequest {
+        self.send_message(&msg);
+        PendingRequest {
+            request : msg,
+            sent_at : Instant::now(),
+        }
     }
+}
+
+
+#[derive(PartialEq, Clone, Debug)]
+/// State of the request that waits for the server
+pub enum Reply {
+    Waiting,
+    Ready(ProtocolMessage),
+    TimedOut,
+}
+
+
+/// Handle of the request sent to the server, is polled every tick
+pub struct PendingRequest {
+    request : ProtocolMessage,
+    sent_at : Instant,
+}
+
 
-    /// Send messages to the server
-    pub fn send_message(&mut self, msg : Vec<u8>) {
-        self.socket.send_message(&Message::binary(msg)).expect("Can't send message");
+impl PendingRequest {
+
+    /// Take the reply from the received messages if it has arrived
+    pub fn poll(&self, client : &mut ClientHandler) -> Reply {
+        if let Some(index) = client.messages.iter().position(|m| m.is_reply_to(&self.request)) {
+            return Reply::Ready(client.messages.remove(index));
+        }
+
+        if self.sent_at.elapsed() > Duration::from_millis(REPLY_TIMEOUT_MS) {
+            Reply::TimedOut
+        } else {
+            Reply::Waiting
+        }
     }
 
+    /// Send the same request again
+    pub fn retry(&mut self, client : &mut ClientHandler) {
+        client.send_message(&self.request);
+        self.sent_at = Instant::now();
+    }
 }
@@ -6,6 +6,10 @@ use rltk::RGB;
 use serde::{Serialize, Deserialize};
 use specs::saveload::{Marker, ConvertSaveload};
 use specs::error::NoError;
+use std::collections::VecDeque;
+use std::time::Instant;
+
+use crate::protocol::AttackerKind;
 
 #[derive(Component, ConvertSaveload, Clone)]
 pub struct Position {
@@ -39,6 +43,14 @@ pub struct Viewshed {
 #[derive(Component, Debug, Serialize, Deserialize, Clone)]
 pub struct Monster {}
 
+#[derive(Component, ConvertSaveload, Clone)]
+/// Place of the monster or the item on the level the player has left, it is frozen there until the player comes back
+pub struct OtherLevelPosition {
+    pub x : i32,
+    pub y : i32,
+    pub depth : i32,
+}
+
 #[derive(Component, Debug, ConvertSaveload, Clone)]
 pub struct Name {
     pub name : String,
@@ -63,15 +75,19 @@ pub struct WantsToMelee {
 #[derive(Component, Debug, ConvertSaveload, Clone)]
 pub struct SufferDamage {
     pub amount : Vec<i32>,
+    /// Names of those who have dealt the damage and whether they are monsters or players
+    pub attackers : Vec<(String, AttackerKind)>,
 }
 
 impl SufferDamage {
-    pub fn new_damage(store : &mut WriteStorage<SufferDamage>, victim : Entity, amount : i32) {
+    pub fn new_damage(store : &mut WriteStorage<SufferDamage>, victim : Entity, amount : i32, attacker : Option<(String, AttackerKind)>) {
         if let Some(suffering) = store.get_mut(victim) {
             suffering.amount.push(amount);
+            suffering.attackers.extend(attacker);
         } else {
             let dmg = SufferDamage {
-                amount : vec![amount]
+                amount : vec![amount],
+                attackers : attacker.into_iter().collect(),
             };
             store.insert(victim, dmg).expect("Unable to insert damage");
         }
@@ -137,6 +153,8 @@ pub struct SerializeMe;
 #[derive(Component, Serialize, Deserialize, Clone)]
 pub struct SerializationHelper {
     pub map : super::map::Map,
+    #[serde(default)]
+    pub levels : super::level_store::LevelStore,
 }
 
 #[derive(PartialEq, Copy, Clone, Serialize, Deserialize)]
@@ -173,3 +191,24 @@ pub struct WantsToRemoveItem {
 
 #[derive(Component, Debug, Clone)]
 pub struct Enemy {}
+
+#[derive(Component, Debug, Clone, Default)]
+/// Movement of the remote player: the tiles it still walks through one by one
+/// and the tile it was seen on last, it is shown there while it is out of view
+pub struct RemoteMotion {
+    pub path : VecDeque<i32>,
+    pub next_step : Option<Instant>,
+    pub last_seen : Option<i32>,
+}
+
+#[derive(Component, Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct NetworkId {
+    pub id : u64,
+}
+
+impl NetworkId {
+    /// Id of the entity spawned with the level, it is the same for every player
+    pub fn level(depth : i32, idx : usize) -> Self {
+        NetworkId { id : ((depth as u64) << 32) | idx as u64 }
+    }
+}
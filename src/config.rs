@@ -0,0 +1,167 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Serialize, Deserialize};
+use url::Url;
+
+use super::CustomizeTiles;
+use crate::map_builders::prefabs::PREFAB_DIR;
+use crate::backends::lagging_backend::NetworkConditions;
+
+
+/// File that is read if `--config` is not passed
+const DEFAULT_CONFIG_FILE : &str = "customize.txt";
+
+/// Server the game connects to if nothing else is set
+const DEFAULT_SERVER : &str = "ws://127.0.0.1:6881";
+
+const USAGE : &str = "Usage: multiplayer-roguelike [OPTIONS]
+
+Options:
+    --config <FILE>    json config file (default: customize.txt)
+    --server <URL>     game server address, e.g. ws://127.0.0.1:6881
+    --name <NAME>      player name, the name entering is skipped
+    --offline          play single player without the server
+    --seed <SEED>      seed of the game, the same seed builds the same levels
+    --prefabs <DIR>    folder of the prefabs, the same as on the server (default: ./assets/prefabs)
+    --record <FILE>    write all messages to and from the server to the file
+    --replay <FILE>    play back the recorded or handwritten messages instead of connecting to the server
+    --lag <MS>         delay every message to and from the server, to debug the laggy network
+    --jitter <MS>      the biggest random delay on top of the lag
+    --drop <RATE>      share of the lost messages, from 0 to 1
+    --reorder <RATE>   share of the messages that come after the next ones, from 0 to 1
+    --network-seed <SEED>  seed of the bad network, the same seed loses and delays the same messages
+    --help             print this message";
+
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+/// Settings of the game client. They are read from the config file first,
+/// the command line arguments override them
+pub struct Config {
+    pub server : String,
+    pub name : Option<String>,
+    pub offline : bool,
+    pub seed : Option<u64>,
+    /// Folder the prefabs are read from
+    pub prefabs : String,
+    /// File the traffic of the server is recorded to
+    pub record : Option<String>,
+    /// Script the fake server plays back instead of the real one
+    pub replay : Option<String>,
+    #[serde(flatten)]
+    pub network : NetworkConditions,
+    #[serde(flatten)]
+    pub tiles : CustomizeTiles,
+}
+
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            server : DEFAULT_SERVER.to_string(),
+            name : None,
+            offline : false,
+            seed : None,
+            prefabs : PREFAB_DIR.to_string(),
+            record : None,
+            replay : None,
+            network : NetworkConditions::default(),
+            tiles : CustomizeTiles::default(),
+        }
+    }
+}
+
+
+impl Config {
+
+    /// Build the config from the command line arguments of the process,
+    /// prints the usage and exits if they are wrong
+    pub fn load() -> Self {
+        match Config::from_args(std::env::args().skip(1)) {
+            Ok(config) => config,
+            Err(e) => {
+                println!("{}\n\n{}", e, USAGE);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    /// Read the config file and apply the arguments on top of it
+    pub fn from_args<I : Iterator<Item = String>>(args : I) -> Result<Self, String> {
+        let args = args.collect::<Vec<_>>();
+
+        // the config file is read first, so that the arguments win
+        let explicit_file = args.iter()
+            .position(|arg| arg == "--config")
+            .map(|i| args.get(i + 1).cloned().ok_or("--config needs a value"))
+            .transpose()?;
+        let mut config = match &explicit_file {
+            Some(path) => Config::from_file(path)?,
+            None if Path::new(DEFAULT_CONFIG_FILE).exists() => Config::from_file(DEFAULT_CONFIG_FILE)?,
+            None => Config::default(),
+        };
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--config" => { args.next(); }
+                "--server" => config.server = args.next().ok_or("--server needs a value")?,
+                "--name" => config.name = Some(args.next().ok_or("--name needs a value")?),
+                "--offline" => config.offline = true,
+                "--seed" => {
+                    let seed = args.next().ok_or("--seed needs a value")?;
+                    config.seed = Some(seed.parse().map_err(|_| format!("Wrong seed: {}", seed))?);
+                }
+                "--prefabs" => config.prefabs = args.next().ok_or("--prefabs needs a value")?,
+                "--record" => config.record = Some(args.next().ok_or("--record needs a value")?),
+                "--replay" => config.replay = Some(args.next().ok_or("--replay needs a value")?),
+                "--lag" => config.network.lag_ms = parse_arg(&arg, args.next())?,
+                "--jitter" => config.network.jitter_ms = parse_arg(&arg, args.next())?,
+                "--drop" => config.network.drop_rate = parse_rate(&arg, args.next())?,
+                "--reorder" => config.network.reorder_rate = parse_rate(&arg, args.next())?,
+                "--network-seed" => config.network.network_seed = Some(parse_arg(&arg, args.next())?),
+                "--help" | "-h" => {
+                    println!("{}", USAGE);
+                    std::process::exit(0);
+                }
+                _ => return Err(format!("Unknown argument: {}", arg)),
+            }
+        }
+
+        if config.name.as_deref() == Some("") {
+            return Err("The name can't be empty".to_string());
+        }
+        config.server_url()?;
+
+        Ok(config)
+    }
+
+    /// Read the json config file, missing fields get the default values
+    fn from_file(path : &str) -> Result<Self, String> {
+        let data = fs::read_to_string(path).map_err(|e| format!("Can't read config {}: {}", path, e))?;
+        serde_json::from_str(&data).map_err(|e| format!("Can't parse config {}: {}", path, e))
+    }
+
+    /// Address of the game server
+    pub fn server_url(&self) -> Result<Url, String> {
+        Url::parse(&self.server).map_err(|e| format!("Wrong server address {}: {}", self.server, e))
+    }
+}
+
+
+/// Value of the numeric argument
+fn parse_arg<T : std::str::FromStr>(arg : &str, value : Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("{} needs a value", arg))?;
+    value.parse().map_err(|_| format!("Wrong value of {}: {}", arg, value))
+}
+
+
+/// Share from 0 to 1 of the argument
+fn parse_rate(arg : &str, value : Option<String>) -> Result<f32, String> {
+    let rate : f32 = parse_arg(arg, value)?;
+    if !(0.0..=1.0).contains(&rate) {
+        return Err(format!("{} has to be from 0 to 1", arg));
+    }
+    Ok(rate)
+}
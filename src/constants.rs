@@ -7,3 +7,18 @@ pub const MAPWIDTH : usize = 80;
 pub const MAPHEIGHT : usize = 43;
 pub const MAPCOUNT : usize = MAPHEIGHT * MAPWIDTH;
 pub const MAX_MONSTERS : i32 = 4;
+pub const REPLY_TIMEOUT_MS : u64 = 5000;
+pub const RECONNECT_MIN_DELAY_MS : u64 = 500;
+pub const RECONNECT_MAX_DELAY_MS : u64 = 10000;
+pub const ROOMS_REFRESH_MS : u64 = 1000;
+pub const PLAYERS_REFRESH_MS : u64 = 1000;
+pub const PLAYER_MAX_HP : i32 = 30;
+pub const MONSTER_MAX_HP : i32 = 16;
+/// The strongest hit in the game, it is the fireball
+pub const MAX_HIT : i32 = 20;
+/// The farthest hit in the game: the scroll is read 6 tiles away and the fireball burns 3 tiles around
+pub const MAX_HIT_DISTANCE : i32 = 9;
+/// Time the remote player takes to walk one tile of the moves that have come together
+pub const MOVE_STEP_MS : u64 = 80;
+/// The most steps of the remote player that wait to be walked, the older ones are skipped
+pub const MAX_MOVE_QUEUE : usize = 4;
@@ -1,4 +1,21 @@
+use std::collections::HashMap;
+
+use rltk::RGB;
+
+
 /// Display the game event chat
 pub struct GameLog {
     pub entries : Vec<String>,
+    /// Colors of the entries that are not white, by the index of the entry
+    pub colors : HashMap<usize, RGB>,
+}
+
+
+impl GameLog {
+
+    /// Add the entry that is drawn in the given color
+    pub fn push_colored(&mut self, entry : String, color : RGB) {
+        self.colors.insert(self.entries.len(), color);
+        self.entries.push(entry);
+    }
 }
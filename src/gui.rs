@@ -5,7 +5,9 @@ use std::cmp;
 use rltk::{RGB, Rltk, Point, VirtualKeyCode};
 use specs::prelude::*;
 
-use crate::{CombatStats, Player, GameLog, Map, Name, Position, xy_idx, State, InBackpack, Viewshed, RunState, Equipped};
+use crate::{CombatStats, Player, GameLog, Map, Name, Position, xy_idx, State, InBackpack, Viewshed, RunState, Equipped, ProtocolMessage, ConnectionStatus};
+use crate::{MAX_ROOM_PLAYERS, GameMode, PlayMode};
+use crate::leaderboard::{LeaderboardEntry, RatingColumn, RATING_PAGE_SIZE, format_date};
 
 
 /// Display level, hp, gamelog and mouse cursor
@@ -18,19 +20,16 @@ pub fn draw_ui(ecs : &World, ctx : &mut Rltk) {
     
     let log = ecs.fetch::<GameLog>();
 
-    let mut y = 44;
-    for s in log.entries.iter().rev() {
-        if y < 49 {
-            ctx.print(2, y, s);
-        }
-        y += 1;
+    for (y, (i, s)) in (44..49).zip(log.entries.iter().enumerate().rev()) {
+        let color = log.colors.get(&i).copied().unwrap_or(RGB::named(rltk::WHITE));
+        ctx.print_color(2, y, color, RGB::named(rltk::BLACK), s);
     }
 
     // draw mouse cursor
     let mouse_pos = ctx.mouse_pos();
     ctx.set_bg(mouse_pos.0, mouse_pos.1, RGB::named(rltk::MAGENTA));
 
-    draw_tooltips(&ecs, ctx);
+    draw_tooltips(ecs, ctx);
 
     let combat_stats = ecs.read_storage::<CombatStats>();
     let players = ecs.read_storage::<Player>();
@@ -41,6 +40,14 @@ pub fn draw_ui(ecs : &World, ctx : &mut Rltk) {
 
         ctx.draw_bar_horizontal(28, 43, 51, stats.hp, stats.max_hp, RGB::named(rltk::RED), RGB::named(rltk::BLACK));
     }
+
+    // draw connection status in the bottom right corner
+    let (status, color) = match *ecs.fetch::<ConnectionStatus>() {
+        ConnectionStatus::Offline => ("Single player".to_string(), RGB::named(rltk::GREY)),
+        ConnectionStatus::Connected => ("Online".to_string(), RGB::named(rltk::GREEN)),
+        ConnectionStatus::Reconnecting { attempt } => (format!("Reconnecting ({})...", attempt), RGB::named(rltk::ORANGE)),
+    };
+    ctx.print_color(78 - status.len() as i32, 49, color, RGB::named(rltk::BLACK), &status);
 }
 
 
@@ -78,29 +85,25 @@ fn draw_tooltips(ecs : &World, ctx : &mut Rltk) {
         if mouse_pos.0 > 40 {
             let arrow_pos = Point::new(mouse_pos.0 - 2, mouse_pos.1);
             let left_x = mouse_pos.0 - width;
-            let mut y = mouse_pos.1;
-            for s in tooltip.iter() {
+            for (y, s) in (mouse_pos.1..).zip(tooltip.iter()) {
                 ctx.print_color(left_x, y, RGB::named(rltk::WHITE), RGB::named(rltk::GREY), s);
                 let padding = (width - s.len() as i32) - 1;
                 for i in 0..padding {
-                    ctx.print_color(arrow_pos.x - i, arrow_pos.y, RGB::named(rltk::WHITE), RGB::named(rltk::GREY), &" ".to_string());
+                    ctx.print_color(arrow_pos.x - i, arrow_pos.y, RGB::named(rltk::WHITE), RGB::named(rltk::GREY), " ");
                 }
-                y += 1;
             }
-            ctx.print_color(arrow_pos.x, arrow_pos.y, RGB::named(rltk::WHITE),RGB::named(rltk::GREY), &"->".to_string());
+            ctx.print_color(arrow_pos.x, arrow_pos.y, RGB::named(rltk::WHITE),RGB::named(rltk::GREY), "->");
         } else {
             let arrow_pos = Point::new(mouse_pos.0 + 1, mouse_pos.1);
             let left_x = mouse_pos.0 + 3;
-            let mut y = mouse_pos.1;
-            for s in tooltip.iter() {
+            for (y, s) in (mouse_pos.1..).zip(tooltip.iter()) {
                 ctx.print_color(left_x + 1, y, RGB::named(rltk::WHITE), RGB::named(rltk::GREY), s);
                 let padding = (width - s.len() as i32) - 1;
                 for i in 0..padding {
-                    ctx.print_color(arrow_pos.x + i + 1, arrow_pos.y, RGB::named(rltk::WHITE), RGB::named(rltk::GREY), &" ".to_string());
+                    ctx.print_color(arrow_pos.x + i + 1, arrow_pos.y, RGB::named(rltk::WHITE), RGB::named(rltk::GREY), " ");
                 }
-                y += 1;
             }
-            ctx.print_color(arrow_pos.x, arrow_pos.y, RGB::named(rltk::WHITE), RGB::named(rltk::GREY), &"<-".to_string());
+            ctx.print_color(arrow_pos.x, arrow_pos.y, RGB::named(rltk::WHITE), RGB::named(rltk::GREY), "<-");
         }
     }
 }
@@ -125,23 +128,21 @@ pub fn show_inventory(gs: &mut State, ctx: &mut Rltk) -> (ItemMenuResult, Option
     let inventory = (&backpack, &names).join().filter(|item| item.0.owner == *player_entity);
     let count = inventory.count();
 
-    let mut y = (25 - (count / 2)) as i32;
+    let y = (25 - (count / 2)) as i32;
     ctx.draw_box(15, y - 2, 31, (count + 3) as i32, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK));
     ctx.print_color(18, y - 2, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), "Inventory");
     ctx.print_color(18, y + count as i32 + 1, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), "ESCAPE to cancel");
 
     // draw all taken items
     let mut equippable : Vec<Entity> = Vec::new();
-    let mut j = 0;
-    for (entity, _pack, name) in (&entities, &backpack, &names).join().filter(|item| item.1.owner == *player_entity) {
-        ctx.set(17, y, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), rltk::to_cp437('('));
-        ctx.set(18, y, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), 97+j as rltk::FontCharType);
-        ctx.set(19, y, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), rltk::to_cp437(')'));
+    for (j, (entity, _pack, name)) in (&entities, &backpack, &names).join().filter(|item| item.1.owner == *player_entity).enumerate() {
+        let row = y + j as i32;
+        ctx.set(17, row, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), rltk::to_cp437('('));
+        ctx.set(18, row, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), 97 + j as rltk::FontCharType);
+        ctx.set(19, row, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), rltk::to_cp437(')'));
 
-        ctx.print(21, y, &name.name.to_string());
+        ctx.print(21, row, &name.name);
         equippable.push(entity);
-        y += 1;
-        j += 1;
     }
 
     match ctx.key {
@@ -175,22 +176,20 @@ pub fn drop_item_menu(gs: &mut State, ctx: &mut Rltk) -> (ItemMenuResult, Option
     let inventory = (&backpack, &names).join().filter(|item| item.0.owner == *player_entity);
     let count = inventory.count();
 
-    let mut y = (25 - (count / 2)) as i32;
+    let y = (25 - (count / 2)) as i32;
     ctx.draw_box(15, y-2, 31, (count+3) as i32, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK));
     ctx.print_color(18, y-2, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), "Drop Which Item?");
     ctx.print_color(18, y+count as i32+1, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), "ESCAPE to cancel");
 
     let mut equippable : Vec<Entity> = Vec::new();
-    let mut j = 0;
-    for (entity, _pack, name) in (&entities, &backpack, &names).join().filter(|item| item.1.owner == *player_entity) {
-        ctx.set(17, y, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), rltk::to_cp437('('));
-        ctx.set(18, y, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), 97+j as rltk::FontCharType);
-        ctx.set(19, y, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), rltk::to_cp437(')'));
+    for (j, (entity, _pack, name)) in (&entities, &backpack, &names).join().filter(|item| item.1.owner == *player_entity).enumerate() {
+        let row = y + j as i32;
+        ctx.set(17, row, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), rltk::to_cp437('('));
+        ctx.set(18, row, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), 97 + j as rltk::FontCharType);
+        ctx.set(19, row, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), rltk::to_cp437(')'));
 
-        ctx.print(21, y, &name.name.to_string());
+        ctx.print(21, row, &name.name);
         equippable.push(entity);
-        y += 1;
-        j += 1;
     }
 
     match ctx.key {
@@ -273,23 +272,21 @@ pub fn remove_item_menu(gs : &mut State, ctx : &mut Rltk) ->  (ItemMenuResult, O
     let count = inventory.count();
 
     // drow box
-    let mut y = (25 - (count / 2)) as i32;
+    let y = (25 - (count / 2)) as i32;
     ctx.draw_box(15, y - 2, 31, (count + 3) as i32, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK));
     ctx.print_color(18, y - 2, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), "Remove Which Item?");
     ctx.print_color(18, y + count as i32 + 1, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), "ESCAPE to cancel");
 
     // print all taken items
     let mut equippable : Vec<Entity> = Vec::new();
-    let mut j = 0;
-    for (entity, _pack, name) in (&entities, &backpack, &names).join() {
-        ctx.set(17, y, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), rltk::to_cp437('('));
-        ctx.set(18, y, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), 97 + j as rltk::FontCharType);
-        ctx.set(19, y, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), rltk::to_cp437(')'));
+    for (j, (entity, _pack, name)) in (&entities, &backpack, &names).join().enumerate() {
+        let row = y + j as i32;
+        ctx.set(17, row, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), rltk::to_cp437('('));
+        ctx.set(18, row, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), 97 + j as rltk::FontCharType);
+        ctx.set(19, row, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), rltk::to_cp437(')'));
 
-        ctx.print(21, y, &name.name.to_string());
+        ctx.print(21, row, &name.name);
         equippable.push(entity);
-        y += 1;
-        j += 1;
     }
 
     match ctx.key {
@@ -315,7 +312,9 @@ pub fn remove_item_menu(gs : &mut State, ctx : &mut Rltk) ->  (ItemMenuResult, O
 #[derive(PartialEq, Copy, Clone)]
 /// Store the abstract player's selections in the menu
 pub enum MainMenuSelection {
-    Play,
+    SinglePlayer,
+    Multiplayer,
+    Spectate,
     SaveGame,
     LoadGame,
     Rating,
@@ -343,34 +342,50 @@ pub fn main_menu(gs : &mut State, ctx : &mut Rltk) -> MainMenuResult {
 
     // draw all variants of menu actions
     if let RunState::MainMenu { menu_selection : selection } = *runstate {
-        if selection == MainMenuSelection::Play {
-            ctx.print_color_centered(24, RGB::named(rltk::MAGENTA), RGB::named(rltk::BLACK), "Play");
+        if selection == MainMenuSelection::SinglePlayer {
+            ctx.print_color_centered(22, RGB::named(rltk::MAGENTA), RGB::named(rltk::BLACK), "Single Player");
+        } else {
+            ctx.print_color_centered(22, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), "Single Player");
+        }
+
+        if selection == MainMenuSelection::Multiplayer {
+            ctx.print_color_centered(24, RGB::named(rltk::MAGENTA), RGB::named(rltk::BLACK), "Multiplayer");
         } else {
-            ctx.print_color_centered(24, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), "Play");
+            ctx.print_color_centered(24, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), "Multiplayer");
         }
         
+        if selection == MainMenuSelection::Spectate {
+            ctx.print_color_centered(26, RGB::named(rltk::MAGENTA), RGB::named(rltk::BLACK), "Spectate");
+        } else {
+            ctx.print_color_centered(26, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), "Spectate");
+        }
+
         if selection == MainMenuSelection::SaveGame {
-            ctx.print_color_centered(26, RGB::named(rltk::MAGENTA), RGB::named(rltk::BLACK), "Save Game");
+            ctx.print_color_centered(28, RGB::named(rltk::MAGENTA), RGB::named(rltk::BLACK), "Save Game");
         } else {
-            ctx.print_color_centered(26, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), "Save Game");
+            ctx.print_color_centered(28, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), "Save Game");
         }
 
         if selection == MainMenuSelection::LoadGame {
-            ctx.print_color_centered(28, RGB::named(rltk::MAGENTA), RGB::named(rltk::BLACK), "Load Game");
+            ctx.print_color_centered(30, RGB::named(rltk::MAGENTA), RGB::named(rltk::BLACK), "Load Game");
         } else {
-            ctx.print_color_centered(28, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), "Load Game");
+            ctx.print_color_centered(30, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), "Load Game");
         }
 
         if selection == MainMenuSelection::Rating {
-            ctx.print_color_centered(30, RGB::named(rltk::MAGENTA), RGB::named(rltk::BLACK), "Rating");
+            ctx.print_color_centered(32, RGB::named(rltk::MAGENTA), RGB::named(rltk::BLACK), "Rating");
         } else {
-            ctx.print_color_centered(30, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), "Rating");
+            ctx.print_color_centered(32, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), "Rating");
         }
 
         if selection == MainMenuSelection::Quit {
-            ctx.print_color_centered(32, RGB::named(rltk::MAGENTA), RGB::named(rltk::BLACK), "Quit");
+            ctx.print_color_centered(34, RGB::named(rltk::MAGENTA), RGB::named(rltk::BLACK), "Quit");
         } else {
-            ctx.print_color_centered(32, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), "Quit");
+            ctx.print_color_centered(34, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), "Quit");
+        }
+
+        if let Some(error) = &gs.network_error {
+            ctx.print_color_centered(38, RGB::named(rltk::RED), RGB::named(rltk::BLACK), error);
         }
 
         match ctx.key {
@@ -379,25 +394,27 @@ pub fn main_menu(gs : &mut State, ctx : &mut Rltk) -> MainMenuResult {
                 match key {
                     VirtualKeyCode::Escape => return MainMenuResult::NoSelection {selected : selection},
                     VirtualKeyCode::Up => {
-                        let newselection;
-                        match selection {
-                            MainMenuSelection::Play => newselection = MainMenuSelection::Quit,
-                            MainMenuSelection::SaveGame => newselection = MainMenuSelection::Play,
-                            MainMenuSelection::LoadGame => newselection = MainMenuSelection::SaveGame,
-                            MainMenuSelection::Rating => newselection = MainMenuSelection::LoadGame,
-                            MainMenuSelection::Quit => newselection = MainMenuSelection::Rating,
-                        }
+                        let newselection = match selection {
+                            MainMenuSelection::SinglePlayer => MainMenuSelection::Quit,
+                            MainMenuSelection::Multiplayer => MainMenuSelection::SinglePlayer,
+                            MainMenuSelection::Spectate => MainMenuSelection::Multiplayer,
+                            MainMenuSelection::SaveGame => MainMenuSelection::Spectate,
+                            MainMenuSelection::LoadGame => MainMenuSelection::SaveGame,
+                            MainMenuSelection::Rating => MainMenuSelection::LoadGame,
+                            MainMenuSelection::Quit => MainMenuSelection::Rating,
+                        };
                         return MainMenuResult::NoSelection { selected : newselection }
                     }
                     VirtualKeyCode::Down => {
-                        let newselection;
-                        match selection {
-                            MainMenuSelection::Play => newselection = MainMenuSelection::SaveGame,
-                            MainMenuSelection::SaveGame => newselection = MainMenuSelection::LoadGame,
-                            MainMenuSelection::LoadGame => newselection = MainMenuSelection::Rating,
-                            MainMenuSelection::Rating => newselection = MainMenuSelection::Quit,
-                            MainMenuSelection::Quit => newselection = MainMenuSelection::Play,
-                        }
+                        let newselection = match selection {
+                            MainMenuSelection::SinglePlayer => MainMenuSelection::Multiplayer,
+                            MainMenuSelection::Multiplayer => MainMenuSelection::Spectate,
+                            MainMenuSelection::Spectate => MainMenuSelection::SaveGame,
+                            MainMenuSelection::SaveGame => MainMenuSelection::LoadGame,
+                            MainMenuSelection::LoadGame => MainMenuSelection::Rating,
+                            MainMenuSelection::Rating => MainMenuSelection::Quit,
+                            MainMenuSelection::Quit => MainMenuSelection::SinglePlayer,
+                        };
                         return MainMenuResult::NoSelection { selected : newselection }
                     }
                     VirtualKeyCode::Return => return MainMenuResult::Selected { selected : selection },
@@ -406,7 +423,7 @@ pub fn main_menu(gs : &mut State, ctx : &mut Rltk) -> MainMenuResult {
             }
         }
     }
-    MainMenuResult::NoSelection { selected : MainMenuSelection::Play }
+    MainMenuResult::NoSelection { selected : MainMenuSelection::SinglePlayer }
 }
 
 
@@ -436,108 +453,613 @@ pub fn game_over(ctx : &mut Rltk) -> GameOverResult {
 
 
 /// Responsible for entering the player's name and displaying it on the screen
-pub fn entering_name<'a>(ctx : &mut Rltk, name : &'a mut String) -> Result<&'a String, ()> {
+pub fn entering_name<'a>(ctx : &mut Rltk, name : &'a mut String) -> Option<&'a String> {
 
     ctx.print_color_centered(5, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), "Please Enter your name");
 
-    match ctx.key {
-        Some(key) => {
-            match key {
-                VirtualKeyCode::A => (*name).push('a'),
-                VirtualKeyCode::B => (*name).push('b'),
-                VirtualKeyCode::C => (*name).push('c'),
-                VirtualKeyCode::D => (*name).push('d'),
-                VirtualKeyCode::E => (*name).push('e'),
-                VirtualKeyCode::F => (*name).push('g'),
-                VirtualKeyCode::G => (*name).push('g'),
-                VirtualKeyCode::H => (*name).push('h'),
-                VirtualKeyCode::I => (*name).push('i'),
-                VirtualKeyCode::J => (*name).push('j'),
-                VirtualKeyCode::K => (*name).push('k'),
-                VirtualKeyCode::L => (*name).push('l'),
-                VirtualKeyCode::M => (*name).push('m'),
-                VirtualKeyCode::N => (*name).push('n'),
-                VirtualKeyCode::O => (*name).push('o'),
-                VirtualKeyCode::P => (*name).push('p'),
-                VirtualKeyCode::Q => (*name).push('q'),
-                VirtualKeyCode::R => (*name).push('r'),
-                VirtualKeyCode::S => (*name).push('s'),
-                VirtualKeyCode::T => (*name).push('t'),
-                VirtualKeyCode::U => (*name).push('u'),
-                VirtualKeyCode::V => (*name).push('v'),
-                VirtualKeyCode::W => (*name).push('w'),
-                VirtualKeyCode::X => (*name).push('x'),
-                VirtualKeyCode::Y => (*name).push('y'),
-                VirtualKeyCode::Z => (*name).push('z'),
-                VirtualKeyCode::Back => {
-                    if !name.is_empty() {
-                        name.pop();
-                    }
+    if let Some(key) = ctx.key {
+        match key {
+            VirtualKeyCode::A => (*name).push('a'),
+            VirtualKeyCode::B => (*name).push('b'),
+            VirtualKeyCode::C => (*name).push('c'),
+            VirtualKeyCode::D => (*name).push('d'),
+            VirtualKeyCode::E => (*name).push('e'),
+            VirtualKeyCode::F => (*name).push('g'),
+            VirtualKeyCode::G => (*name).push('g'),
+            VirtualKeyCode::H => (*name).push('h'),
+            VirtualKeyCode::I => (*name).push('i'),
+            VirtualKeyCode::J => (*name).push('j'),
+            VirtualKeyCode::K => (*name).push('k'),
+            VirtualKeyCode::L => (*name).push('l'),
+            VirtualKeyCode::M => (*name).push('m'),
+            VirtualKeyCode::N => (*name).push('n'),
+            VirtualKeyCode::O => (*name).push('o'),
+            VirtualKeyCode::P => (*name).push('p'),
+            VirtualKeyCode::Q => (*name).push('q'),
+            VirtualKeyCode::R => (*name).push('r'),
+            VirtualKeyCode::S => (*name).push('s'),
+            VirtualKeyCode::T => (*name).push('t'),
+            VirtualKeyCode::U => (*name).push('u'),
+            VirtualKeyCode::V => (*name).push('v'),
+            VirtualKeyCode::W => (*name).push('w'),
+            VirtualKeyCode::X => (*name).push('x'),
+            VirtualKeyCode::Y => (*name).push('y'),
+            VirtualKeyCode::Z => (*name).push('z'),
+            VirtualKeyCode::Back => {
+                if !name.is_empty() {
+                    name.pop();
                 }
-                VirtualKeyCode::Return => return Ok(name),
-                _ => ctx.print_color_centered(12, RGB::named(rltk::RED), RGB::named(rltk::BLACK), "Error!"),
             }
+            VirtualKeyCode::Return => return Some(name),
+            _ => ctx.print_color_centered(12, RGB::named(rltk::RED), RGB::named(rltk::BLACK), "Error!"),
         }
-        None => (),
     }
     ctx.print_color_centered(7, RGB::named(rltk::WHITESMOKE), RGB::named(rltk::BLACK), format!("Your current name: {}", name));
     ctx.print_color_centered(9, RGB::named(rltk::ORANGE), RGB::named(rltk::BLACK), "Tap Enter to save");
 
-    Err(())
+    None
+}
+
+
+#[derive(PartialEq, Copy, Clone)]
+/// Store the player's choice how to play with the entered name
+pub enum AccountSelection {
+    Guest,
+    Login,
+    Register,
+}
+
+
+#[derive(PartialEq, Copy, Clone)]
+/// Store player's action in the account menu
+pub enum AccountMenuResult {
+    NoSelection {
+        selected : AccountSelection,
+    },
+    Selected {
+        selected : AccountSelection,
+    },
+    Back,
 }
 
 
-/// Draw a box with the current real-time rating of the game
-pub fn show_rating(gs: &mut State, ctx: &mut Rltk) -> ItemMenuResult {
-    let message = b"{\"__RATING__\":\"\"}".to_vec();
-    gs.game_client.send_message(message);
+/// Display the choice to play as a guest, log in to the account of the name or register it
+pub fn account_menu(ctx : &mut Rltk, name : &str, selection : AccountSelection) -> AccountMenuResult {
+    ctx.print_color_centered(5, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), format!("Play as {}", name));
 
-    let clone = gs.game_client.messages.clone();
+    let options = [
+        (AccountSelection::Guest, "Guest, the name is free again after the game"),
+        (AccountSelection::Login, "Log in to my account"),
+        (AccountSelection::Register, "Register the name, nobody else can take it"),
+    ];
+    for (i, (option, label)) in options.iter().enumerate() {
+        let color = if *option == selection { RGB::named(rltk::MAGENTA) } else { RGB::named(rltk::WHITE) };
+        ctx.print_color_centered(9 + 2 * i as i32, color, RGB::named(rltk::BLACK), label);
+    }
+    ctx.print_color_centered(17, RGB::named(rltk::ORANGE), RGB::named(rltk::BLACK), "ENTER to choose, ESCAPE to change the name");
 
-    let response = clone.into_iter().filter(|(key, _)| *key == "__RATING__").collect::<Vec<_>>();
+    match ctx.key {
+        Some(VirtualKeyCode::Up) => {
+            let selected = match selection {
+                AccountSelection::Guest => AccountSelection::Register,
+                AccountSelection::Login => AccountSelection::Guest,
+                AccountSelection::Register => AccountSelection::Login,
+            };
+            AccountMenuResult::NoSelection { selected }
+        }
+        Some(VirtualKeyCode::Down) => {
+            let selected = match selection {
+                AccountSelection::Guest => AccountSelection::Login,
+                AccountSelection::Login => AccountSelection::Register,
+                AccountSelection::Register => AccountSelection::Guest,
+            };
+            AccountMenuResult::NoSelection { selected }
+        }
+        Some(VirtualKeyCode::Return) => AccountMenuResult::Selected { selected : selection },
+        Some(VirtualKeyCode::Escape) => AccountMenuResult::Back,
+        _ => AccountMenuResult::NoSelection { selected : selection },
+    }
+}
 
-    println!("name resp size: {}", response.len());
 
-    let mut r = Vec::<(String, i32)>::new();
+#[derive(PartialEq, Copy, Clone)]
+/// Store player's action while entering the password
+pub enum PasswordResult {
+    NoResponse,
+    Entered,
+    Back,
+}
 
-    // parsing response
-    if !response.is_empty() {
-        let value = &response[0].1;
-        let split = value.split(' ');
-        let v = split.collect::<Vec<&str>>();
-        for record in v {
-            let split = record.split(':');
-            let v = split.collect::<Vec<&str>>();
-            if v.len() > 1 {
-                let value = v[1].parse::<i32>().expect("Can't convert to number");
-                r.push((v[0].to_string(), value));
+
+/// Display the password entering, the password is shown as stars and takes letters and digits
+pub fn entering_password(ctx : &mut Rltk, password : &mut String, register : bool) -> PasswordResult {
+    let title = if register { "Choose the password of the new account" } else { "Please enter your password" };
+    ctx.print_color_centered(5, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), title);
+    ctx.print_color_centered(7, RGB::named(rltk::WHITESMOKE), RGB::named(rltk::BLACK), format!("Password: {}_", "*".repeat(password.len())));
+    ctx.print_color_centered(9, RGB::named(rltk::ORANGE), RGB::named(rltk::BLACK), "ENTER to log in, ESCAPE to go back");
+    // the protocol is plain ws://, everybody on the way to the server can read the password
+    ctx.print_color_centered(11, RGB::named(rltk::GREY), RGB::named(rltk::BLACK), "The connection isn't encrypted, don't use a password from anywhere else");
+
+    match ctx.key {
+        Some(VirtualKeyCode::Return) if !password.is_empty() => return PasswordResult::Entered,
+        Some(VirtualKeyCode::Escape) => return PasswordResult::Back,
+        Some(VirtualKeyCode::Back) => { password.pop(); }
+        Some(key) => {
+            if let Some(c) = typed_char(key) {
+                password.push(c);
             }
         }
+        None => {}
     }
-    
-    // sorting vector by second value from high to low (level)
-    r.sort_by_key(|k| cmp::Reverse(k.1));
+    PasswordResult::NoResponse
+}
 
-    let count = r.len();
 
-    let mut y = (25 - (count / 2)) as i32;
-    ctx.draw_box(15, y - 2, 31, (count + 3) as i32, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK));
-    ctx.print_color(18, y - 2, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), "Rating");
-    ctx.print_color(18, y + count as i32 + 1, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), "ESCAPE to cancel");
+/// Sorting and the page of the rating screen, the entries come from the server
+pub struct RatingView {
+    pub column : RatingColumn,
+    pub descending : bool,
+    pub page : u32,
+    /// Room of the last multiplayer game, its games are shown unless all rooms are asked for
+    pub room : Option<String>,
+    pub all_rooms : bool,
+    pub entries : Vec<LeaderboardEntry>,
+    pub total : u32,
+    /// The page of the current sorting has been asked for
+    pub requested : bool,
+}
+
+
+impl Default for RatingView {
 
-    let x = 20;
-    for record in r {
-        ctx.print_color(x, y, RGB::named(rltk::AQUA), RGB::named(rltk::BLACK), format!("{}: {}", record.0, record.1));
-        y += 1;
+    /// The best players first
+    fn default() -> Self {
+        RatingView {
+            column : RatingColumn::default(),
+            descending : RatingColumn::default().descending_by_default(),
+            page : 0,
+            room : None,
+            all_rooms : true,
+            entries : Vec::new(),
+            total : 0,
+            requested : false,
+        }
+    }
+}
+
+
+impl RatingView {
+
+    /// Request of the page with the current sorting
+    fn request(&self) -> ProtocolMessage {
+        ProtocolMessage::Rating {
+            column : self.column,
+            descending : self.descending,
+            page : self.page,
+            room : self.room.clone(),
+            all_rooms : self.all_rooms,
+            entries : Vec::new(),
+            total : 0,
+        }
+    }
+
+    /// Number of the pages, there is at least one empty page
+    fn pages(&self) -> u32 {
+        (self.total as usize).div_ceil(RATING_PAGE_SIZE).max(1) as u32
+    }
+
+    /// Sort by the next or the previous column
+    fn switch_column(&mut self, step : i32) {
+        let count = RatingColumn::ALL.len() as i32;
+        let current = RatingColumn::ALL.iter().position(|c| *c == self.column).unwrap_or_default() as i32;
+        self.column = RatingColumn::ALL[(current + step).rem_euclid(count) as usize];
+        self.descending = self.column.descending_by_default();
+        self.page = 0;
+    }
+}
+
+
+/// Draw the leaderboard of the finished games, it can be sorted by every column and is shown by pages
+pub fn show_rating(gs : &mut State, ctx : &mut Rltk) -> ItemMenuResult {
+    let view = &mut gs.rating;
+    if !view.requested {
+        gs.game_client.send_message(&view.request());
+        view.requested = true;
+    }
+
+    let request = view.request();
+    if let Some(ProtocolMessage::Rating { entries, total, .. }) = gs.game_client.messages.iter().find(|m| m.is_reply_to(&request)) {
+        view.entries = entries.clone();
+        view.total = *total;
+    }
+
+    let y = 5;
+    ctx.draw_box(2, y - 2, 75, (RATING_PAGE_SIZE + 6) as i32, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK));
+    let title = match &view.room {
+        Some(room) if !view.all_rooms => format!("Rating of the room {}", room),
+        _ => "Rating of all rooms".to_string(),
+    };
+    ctx.print_color(5, y - 2, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), title);
+
+    // the header, the sorting column has the arrow of the order
+    let widths = [15, 5, 5, 6, 25, 10];
+    let mut x = 4;
+    for (column, width) in RatingColumn::ALL.iter().zip(widths) {
+        let (color, label) = if *column == view.column {
+            let arrow = if view.descending { "v" } else { "^" };
+            (RGB::named(rltk::MAGENTA), format!("{}{}", column.label(), arrow))
+        } else {
+            (RGB::named(rltk::YELLOW), column.label().to_string())
+        };
+        ctx.print_color(x, y, color, RGB::named(rltk::BLACK), label);
+        x += width + 1;
+    }
+
+    if view.total == 0 {
+        ctx.print_color(4, y + 2, RGB::named(rltk::GREY), RGB::named(rltk::BLACK), "Nobody has finished the game yet");
+    }
+    for (row, entry) in (y + 2..).zip(view.entries.iter()) {
+        let line = format!("{:<15.15} {:>5} {:>5} {:>6} {:<25.25} {:<10}",
+            entry.name, entry.max_depth, entry.kills, entry.turns, entry.cause, format_date(entry.date));
+        ctx.print_color(4, row, RGB::named(rltk::AQUA), RGB::named(rltk::BLACK), line);
+    }
+
+    let footer = y + RATING_PAGE_SIZE as i32 + 3;
+    ctx.print_color(60, footer - 1, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), format!("Page {} / {}", view.page + 1, view.pages()));
+    ctx.print_color(4, footer, RGB::named(rltk::ORANGE), RGB::named(rltk::BLACK), "LEFT/RIGHT sort, TAB reverse, UP/DOWN page, A all rooms, ESCAPE cancel");
+    if let Some(error) = &gs.network_error {
+        ctx.print_color_centered(footer + 3, RGB::named(rltk::RED), RGB::named(rltk::BLACK), error);
     }
 
     match ctx.key {
-        None => ItemMenuResult::NoResponse,
-        Some(key) => {
-            match key {
-                VirtualKeyCode::Escape => ItemMenuResult::Cancel,
-                _ => ItemMenuResult::NoResponse,
+        Some(VirtualKeyCode::Escape) => return ItemMenuResult::Cancel,
+        Some(VirtualKeyCode::Left) => view.switch_column(-1),
+        Some(VirtualKeyCode::Right) => view.switch_column(1),
+        Some(VirtualKeyCode::Tab) => {
+            view.descending = !view.descending;
+            view.page = 0;
+        }
+        Some(VirtualKeyCode::A) if view.room.is_some() => {
+            view.all_rooms = !view.all_rooms;
+            view.page = 0;
+        }
+        Some(VirtualKeyCode::Up) | Some(VirtualKeyCode::PageUp) if view.page > 0 => view.page -= 1,
+        Some(VirtualKeyCode::Down) | Some(VirtualKeyCode::PageDown) if view.page + 1 < view.pages() => view.page += 1,
+        _ => return ItemMenuResult::NoResponse,
+    }
+    view.requested = false;
+    ItemMenuResult::NoResponse
+}
+
+
+#[derive(PartialEq, Copy, Clone)]
+/// Store player's action while the game waits for the server
+pub enum WaitingResult {
+    NoResponse,
+    Retry,
+    Cancel,
+}
+
+
+/// Display the box while the game waits for the server's reply
+pub fn waiting_for_server(ctx : &mut Rltk, title : &str, timed_out : bool) -> WaitingResult {
+    ctx.draw_box(15, 19, 49, 6, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK));
+    ctx.print_color_centered(21, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), "Connecting...");
+    ctx.print_color_centered(22, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), title);
+
+    if timed_out {
+        ctx.print_color_centered(23, RGB::named(rltk::RED), RGB::named(rltk::BLACK), "The server does not respond");
+        ctx.print_color_centered(24, RGB::named(rltk::MAGENTA), RGB::named(rltk::BLACK), "R to retry, ESCAPE to cancel");
+    } else {
+        ctx.print_color_centered(24, RGB::named(rltk::MAGENTA), RGB::named(rltk::BLACK), "ESCAPE to cancel");
+    }
+
+    match ctx.key {
+        Some(VirtualKeyCode::Escape) => WaitingResult::Cancel,
+        Some(VirtualKeyCode::R) if timed_out => WaitingResult::Retry,
+        _ => WaitingResult::NoResponse,
+    }
+}
+
+
+#[derive(PartialEq, Clone)]
+/// Store player's action in the lobby
+pub enum LobbyResult {
+    NoResponse {
+        selection : usize,
+    },
+    Join {
+        room : String,
+    },
+    Create,
+    Cancel,
+}
+
+
+/// Display the list of the open rooms and reacts to the player's following actions
+pub fn lobby(gs : &mut State, ctx : &mut Rltk, selection : usize) -> LobbyResult {
+    let count = gs.rooms.len();
+    let selection = selection.min(count.saturating_sub(1));
+
+    let spectator = gs.play_mode == Some(PlayMode::Spectator);
+
+    ctx.print_color_centered(5, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), "Lobby");
+    if count == 0 && spectator {
+        ctx.print_color_centered(9, RGB::named(rltk::GREY), RGB::named(rltk::BLACK), "There are no rooms yet, nobody to watch");
+    } else if count == 0 {
+        ctx.print_color_centered(9, RGB::named(rltk::GREY), RGB::named(rltk::BLACK), "There are no rooms yet, create the first one");
+    }
+
+    for (i, room) in gs.rooms.iter().enumerate() {
+        let line = format!("{:<30} {:>2} / {:<2} {}", room.name, room.players, room.max_players, room.mode.label());
+        let color = if i == selection {
+            RGB::named(rltk::MAGENTA)
+        } else if room.players >= room.max_players && !spectator {
+            RGB::named(rltk::GREY)
+        } else {
+            RGB::named(rltk::WHITE)
+        };
+        ctx.print_color(20, 9 + i as i32, color, RGB::named(rltk::BLACK), line);
+    }
+
+    if let Some(error) = &gs.network_error {
+        ctx.print_color_centered(40, RGB::named(rltk::RED), RGB::named(rltk::BLACK), error);
+    }
+    if spectator {
+        ctx.print_color_centered(42, RGB::named(rltk::ORANGE), RGB::named(rltk::BLACK), "ENTER to watch the room, ESCAPE to leave");
+    } else {
+        ctx.print_color_centered(42, RGB::named(rltk::ORANGE), RGB::named(rltk::BLACK), "ENTER to join, C to create a room, ESCAPE to leave");
+    }
+
+    match ctx.key {
+        Some(VirtualKeyCode::Up) => LobbyResult::NoResponse { selection : selection.saturating_sub(1) },
+        Some(VirtualKeyCode::Down) => LobbyResult::NoResponse { selection : cmp::min(selection + 1, count.saturating_sub(1)) },
+        Some(VirtualKeyCode::Return) if count > 0 => LobbyResult::Join { room : gs.rooms[selection].name.clone() },
+        Some(VirtualKeyCode::C) if !spectator => LobbyResult::Create,
+        Some(VirtualKeyCode::Escape) => LobbyResult::Cancel,
+        _ => LobbyResult::NoResponse { selection },
+    }
+}
+
+
+#[derive(PartialEq, Copy, Clone)]
+/// Field of the room form that gets the typed keys
+pub enum RoomFormField {
+    Name,
+    MaxPlayers,
+    Mode,
+    Seed,
+}
+
+
+/// Settings of the room the player is creating
+pub struct RoomForm {
+    pub name : String,
+    pub max_players : u32,
+    pub mode : GameMode,
+    pub seed : String,
+    pub field : RoomFormField,
+}
+
+
+impl RoomForm {
+
+    /// Empty form, the seed from the config is offered
+    pub fn new(seed : Option<u64>) -> Self {
+        RoomForm {
+            name : String::new(),
+            max_players : 4,
+            mode : GameMode::Coop,
+            seed : seed.map(|seed| seed.to_string()).unwrap_or_default(),
+            field : RoomFormField::Name,
+        }
+    }
+
+    /// Request to create the room, the server picks the seed if it is empty
+    pub fn request(&self) -> ProtocolMessage {
+        ProtocolMessage::CreateRoom {
+            room : self.name.clone(),
+            max_players : self.max_players,
+            mode : self.mode,
+            seed : self.seed.parse().ok(),
+            error : None,
+        }
+    }
+}
+
+
+#[derive(PartialEq, Copy, Clone)]
+/// Store player's action in the room form
+pub enum RoomFormResult {
+    NoResponse,
+    Create,
+    Cancel,
+}
+
+
+/// Display the form of the new room: TAB switches the fields,
+/// the name takes letters and digits, the seed takes digits, the max players and the mode are changed with arrows
+pub fn create_room(ctx : &mut Rltk, form : &mut RoomForm) -> RoomFormResult {
+    ctx.print_color_centered(5, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), "New room");
+
+    let fields = [
+        (RoomFormField::Name, format!("Name: {}", form.name)),
+        (RoomFormField::MaxPlayers, format!("Max players: < {} >", form.max_players)),
+        (RoomFormField::Mode, format!("Mode: < {} >", form.mode.label())),
+        (RoomFormField::Seed, format!("Seed: {}", if form.seed.is_empty() { "random" } else { &form.seed })),
+    ];
+    for (row, (field, line)) in (9..).step_by(2).zip(fields.iter()) {
+        let color = if *field == form.field { RGB::named(rltk::MAGENTA) } else { RGB::named(rltk::WHITE) };
+        ctx.print_color(25, row, color, RGB::named(rltk::BLACK), line);
+    }
+    ctx.print_color_centered(19, RGB::named(rltk::ORANGE), RGB::named(rltk::BLACK), "TAB to switch, ENTER to create, ESCAPE to cancel");
+
+    let key = match ctx.key {
+        Some(key) => key,
+        None => return RoomFormResult::NoResponse,
+    };
+
+    match (key, form.field) {
+        (VirtualKeyCode::Escape, _) => return RoomFormResult::Cancel,
+        (VirtualKeyCode::Return, _) if !form.name.is_empty() => return RoomFormResult::Create,
+        (VirtualKeyCode::Tab, _) => {
+            form.field = match form.field {
+                RoomFormField::Name => RoomFormField::MaxPlayers,
+                RoomFormField::MaxPlayers => RoomFormField::Mode,
+                RoomFormField::Mode => RoomFormField::Seed,
+                RoomFormField::Seed => RoomFormField::Name,
+            };
+        }
+        (VirtualKeyCode::Left, RoomFormField::MaxPlayers) => form.max_players = cmp::max(form.max_players - 1, 1),
+        (VirtualKeyCode::Right, RoomFormField::MaxPlayers) => form.max_players = cmp::min(form.max_players + 1, MAX_ROOM_PLAYERS),
+        (VirtualKeyCode::Left, RoomFormField::Mode) | (VirtualKeyCode::Right, RoomFormField::Mode) => {
+            form.mode = match form.mode {
+                GameMode::Coop => GameMode::Pvp,
+                GameMode::Pvp => GameMode::Coop,
+            };
+        }
+        (VirtualKeyCode::Back, RoomFormField::Name) => { form.name.pop(); }
+        (VirtualKeyCode::Back, RoomFormField::Seed) => { form.seed.pop(); }
+        (key, RoomFormField::Name) => {
+            if let Some(c) = typed_char(key) {
+                form.name.push(c);
             }
         }
+        (key, RoomFormField::Seed) => {
+            // u64 has at most 20 digits
+            if let Some(c) = typed_char(key).filter(|c| c.is_ascii_digit() && form.seed.len() < 20) {
+                form.seed.push(c);
+            }
+        }
+        _ => {}
+    }
+    RoomFormResult::NoResponse
+}
+
+
+/// Character of the letter or digit key
+fn typed_char(key : VirtualKeyCode) -> Option<char> {
+    let letters = [
+        VirtualKeyCode::A, VirtualKeyCode::B, VirtualKeyCode::C, VirtualKeyCode::D, VirtualKeyCode::E,
+        VirtualKeyCode::F, VirtualKeyCode::G, VirtualKeyCode::H, VirtualKeyCode::I, VirtualKeyCode::J,
+        VirtualKeyCode::K, VirtualKeyCode::L, VirtualKeyCode::M, VirtualKeyCode::N, VirtualKeyCode::O,
+        VirtualKeyCode::P, VirtualKeyCode::Q, VirtualKeyCode::R, VirtualKeyCode::S, VirtualKeyCode::T,
+        VirtualKeyCode::U, VirtualKeyCode::V, VirtualKeyCode::W, VirtualKeyCode::X, VirtualKeyCode::Y,
+        VirtualKeyCode::Z,
+    ];
+    let digits = [
+        VirtualKeyCode::Key0, VirtualKeyCode::Key1, VirtualKeyCode::Key2, VirtualKeyCode::Key3, VirtualKeyCode::Key4,
+        VirtualKeyCode::Key5, VirtualKeyCode::Key6, VirtualKeyCode::Key7, VirtualKeyCode::Key8, VirtualKeyCode::Key9,
+    ];
+
+    if let Some(i) = letters.iter().position(|k| *k == key) {
+        return Some((b'a' + i as u8) as char);
+    }
+    digits.iter().position(|k| *k == key).map(|i| (b'0' + i as u8) as char)
+}
+
+
+#[derive(PartialEq, Copy, Clone)]
+/// Who gets the chat line
+pub enum ChatScope {
+    Depth,
+    All,
+}
+
+
+impl ChatScope {
+
+    /// Color of the chat lines with this scope in the log
+    pub fn color(&self) -> RGB {
+        match self {
+            ChatScope::Depth => RGB::named(rltk::LIGHT_GREEN),
+            ChatScope::All => RGB::named(rltk::CYAN),
+        }
+    }
+
+    /// Label of the scope in the log and in the input line
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChatScope::Depth => "depth",
+            ChatScope::All => "all",
+        }
+    }
+}
+
+
+#[derive(PartialEq, Copy, Clone)]
+/// Store player's action in the chat input
+pub enum ChatResult {
+    NoResponse,
+    Send,
+    Cancel,
+}
+
+
+/// Longest chat line that fits into the log
+const MAX_CHAT_LENGTH : usize = 60;
+
+
+/// Display the chat input line above the log, TAB switches the scope
+pub fn chat_input(ctx : &mut Rltk, text : &mut String, scope : &mut ChatScope) -> ChatResult {
+    let line = format!("Say ({}): {}_", scope.label(), text);
+    ctx.print_color(1, 42, scope.color(), RGB::named(rltk::BLACK), format!("{:<78}", line));
+
+    let key = match ctx.key {
+        Some(key) => key,
+        None => return ChatResult::NoResponse,
+    };
+
+    match key {
+        VirtualKeyCode::Escape => return ChatResult::Cancel,
+        VirtualKeyCode::Return if !text.trim().is_empty() => return ChatResult::Send,
+        VirtualKeyCode::Tab => {
+            *scope = match scope {
+                ChatScope::Depth => ChatScope::All,
+                ChatScope::All => ChatScope::Depth,
+            };
+        }
+        VirtualKeyCode::Back => { text.pop(); }
+        key if text.len() < MAX_CHAT_LENGTH => {
+            let c = match key {
+                VirtualKeyCode::Space => Some(' '),
+                VirtualKeyCode::Comma => Some(','),
+                VirtualKeyCode::Period => Some('.'),
+                key => typed_char(key),
+            };
+            if let Some(c) = c {
+                text.push(c);
+            }
+        }
+        _ => {}
+    }
+    ChatResult::NoResponse
+}
+
+
+#[derive(PartialEq, Copy, Clone)]
+/// Store spectator's action while watching the game
+pub enum SpectateResult {
+    NoResponse,
+    Next,
+    Previous,
+    Leave,
+}
+
+
+/// Display the name of the watched player instead of the hp, the arrows switch between the players
+pub fn spectating(ctx : &mut Rltk, watched : Option<&str>) -> SpectateResult {
+    let line = match watched {
+        Some(name) => format!(" Watching {}", name),
+        None => " Nobody plays in this room yet".to_string(),
+    };
+    ctx.print_color(12, 43, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), line);
+    ctx.print_color_centered(42, RGB::named(rltk::ORANGE), RGB::named(rltk::BLACK), "LEFT / RIGHT to switch the player, ESCAPE to leave");
+
+    match ctx.key {
+        Some(VirtualKeyCode::Right) | Some(VirtualKeyCode::Tab) => SpectateResult::Next,
+        Some(VirtualKeyCode::Left) => SpectateResult::Previous,
+        Some(VirtualKeyCode::Escape) => SpectateResult::Leave,
+        _ => SpectateResult::NoResponse,
     }
 }
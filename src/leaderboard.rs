@@ -0,0 +1,225 @@
+// Leaderboard of the finished games. The server keeps it on disk, the single player game in memory
+
+use std::cmp::Ordering;
+use std::fs;
+use std::path::Path;
+
+use serde::{Serialize, Deserialize};
+
+
+/// Number of the entries on one page of the rating screen
+pub const RATING_PAGE_SIZE : usize = 15;
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+/// One finished game of the player
+pub struct LeaderboardEntry {
+    pub name : String,
+    /// Room the game was played in, the single player games have none
+    #[serde(default)]
+    pub room : String,
+    pub max_depth : i32,
+    pub kills : u32,
+    pub turns : u32,
+    pub cause : String,
+    /// Unix time of the death in seconds
+    pub date : u64,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+/// Column the leaderboard is sorted by
+pub enum RatingColumn {
+    Name,
+    #[default]
+    MaxDepth,
+    Kills,
+    Turns,
+    Cause,
+    Date,
+}
+
+
+impl RatingColumn {
+
+    /// All columns in the order of the rating screen
+    pub const ALL : [RatingColumn; 6] = [
+        RatingColumn::Name, RatingColumn::MaxDepth, RatingColumn::Kills,
+        RatingColumn::Turns, RatingColumn::Cause, RatingColumn::Date,
+    ];
+
+    /// Header of the column on the rating screen
+    pub fn label(&self) -> &'static str {
+        match self {
+            RatingColumn::Name => "Name",
+            RatingColumn::MaxDepth => "Depth",
+            RatingColumn::Kills => "Kills",
+            RatingColumn::Turns => "Turns",
+            RatingColumn::Cause => "Cause of death",
+            RatingColumn::Date => "Date",
+        }
+    }
+
+    /// The text columns go from A to Z, the numbers from the best to the worst
+    pub fn descending_by_default(&self) -> bool {
+        !matches!(self, RatingColumn::Name | RatingColumn::Cause)
+    }
+
+    /// Compare two entries by this column
+    fn compare(&self, a : &LeaderboardEntry, b : &LeaderboardEntry) -> Ordering {
+        match self {
+            RatingColumn::Name => a.name.cmp(&b.name),
+            RatingColumn::MaxDepth => a.max_depth.cmp(&b.max_depth),
+            RatingColumn::Kills => a.kills.cmp(&b.kills),
+            RatingColumn::Turns => a.turns.cmp(&b.turns),
+            RatingColumn::Cause => a.cause.cmp(&b.cause),
+            RatingColumn::Date => a.date.cmp(&b.date),
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+/// All finished games
+pub struct Leaderboard {
+    pub entries : Vec<LeaderboardEntry>,
+}
+
+
+impl Leaderboard {
+
+    /// Read the leaderboard from the json file, there is an empty one if the file doesn't exist yet
+    pub fn load(path : &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Leaderboard::default());
+        }
+        let data = fs::read_to_string(path).map_err(|e| format!("Can't read leaderboard {}: {}", path.display(), e))?;
+        serde_json::from_str(&data).map_err(|e| format!("Can't parse leaderboard {}: {}", path.display(), e))
+    }
+
+    /// Write the leaderboard to the json file
+    pub fn save(&self, path : &Path) -> Result<(), String> {
+        let data = serde_json::to_string_pretty(self).expect("Can't serialize leaderboard");
+        fs::write(path, data).map_err(|e| format!("Can't write leaderboard {}: {}", path.display(), e))
+    }
+
+    /// One page of the entries of the room sorted by the column and the total number of the entries of the room,
+    /// all rooms are taken without it. The ties keep the order of the best depth
+    pub fn page(&self, room : Option<&str>, column : RatingColumn, descending : bool, page : u32) -> (Vec<LeaderboardEntry>, u32) {
+        let mut sorted = self.entries.iter()
+            .filter(|entry| room.is_none_or(|room| entry.room == room))
+            .cloned()
+            .collect::<Vec<_>>();
+        let total = sorted.len() as u32;
+        sorted.sort_by(|a, b| {
+            let order = column.compare(a, b);
+            let order = if descending { order.reverse() } else { order };
+            order.then_with(|| b.max_depth.cmp(&a.max_depth))
+        });
+
+        let entries = sorted.into_iter()
+            .skip(page as usize * RATING_PAGE_SIZE)
+            .take(RATING_PAGE_SIZE)
+            .collect();
+        (entries, total)
+    }
+}
+
+
+/// Date of the unix time in the `YYYY-MM-DD` format
+pub fn format_date(time : u64) -> String {
+    // days to the civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let days = (time / 86400) as i64 + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The board of the given number of the games, the game `i` is `i` levels deep and has `i % 4` kills
+    fn board(games : u32, room : &str) -> Leaderboard {
+        let entries = (1..=games)
+            .map(|i| LeaderboardEntry {
+                name : format!("player{:02}", i),
+                room : room.to_string(),
+                max_depth : i as i32,
+                kills : i % 4,
+                turns : 100 * i,
+                cause : String::new(),
+                date : i as u64,
+            })
+            .collect();
+        Leaderboard { entries }
+    }
+
+    fn depths(entries : &[LeaderboardEntry]) -> Vec<i32> {
+        entries.iter().map(|entry| entry.max_depth).collect()
+    }
+
+    #[test]
+    /// The column chooses the order, the flag turns it around
+    fn sort_order_and_descending_flag() {
+        let board = board(5, "");
+        let (entries, _) = board.page(None, RatingColumn::MaxDepth, true, 0);
+        assert_eq!(depths(&entries), vec![5, 4, 3, 2, 1]);
+        let (entries, _) = board.page(None, RatingColumn::MaxDepth, false, 0);
+        assert_eq!(depths(&entries), vec![1, 2, 3, 4, 5]);
+        let (entries, _) = board.page(None, RatingColumn::Name, false, 0);
+        assert_eq!(entries.first().map(|entry| entry.name.as_str()), Some("player01"));
+    }
+
+    #[test]
+    /// The equal values keep the deepest game first whatever the direction
+    fn ties_keep_the_deepest_first() {
+        let board = board(8, "");
+        // the kills are 1 2 3 0 1 2 3 0 for the depths 1 to 8
+        let (entries, _) = board.page(None, RatingColumn::Kills, true, 0);
+        assert_eq!(depths(&entries), vec![7, 3, 6, 2, 5, 1, 8, 4]);
+        let (entries, _) = board.page(None, RatingColumn::Kills, false, 0);
+        assert_eq!(depths(&entries), vec![8, 4, 5, 1, 6, 2, 7, 3]);
+    }
+
+    #[test]
+    /// The last page has the rest of the games, the page past the end has none
+    fn pages() {
+        let board = board(RATING_PAGE_SIZE as u32 * 2 + 4, "");
+        let (first, total) = board.page(None, RatingColumn::MaxDepth, true, 0);
+        assert_eq!((first.len(), total), (RATING_PAGE_SIZE, 34));
+        assert_eq!(first.first().map(|entry| entry.max_depth), Some(34));
+
+        let (last, total) = board.page(None, RatingColumn::MaxDepth, true, 2);
+        assert_eq!((depths(&last), total), (vec![4, 3, 2, 1], 34));
+
+        let (past, total) = board.page(None, RatingColumn::MaxDepth, true, 3);
+        assert_eq!((past.len(), total), (0, 34));
+
+        let (full, _) = self::board(RATING_PAGE_SIZE as u32, "").page(None, RatingColumn::MaxDepth, true, 1);
+        assert!(full.is_empty());
+    }
+
+    #[test]
+    /// The room gets only its own games, all rooms are shown without it
+    fn rooms() {
+        let mut board = board(3, "cave");
+        board.entries.extend(self::board(2, "tower").entries);
+
+        let (entries, total) = board.page(Some("tower"), RatingColumn::MaxDepth, true, 0);
+        assert_eq!((depths(&entries), total), (vec![2, 1], 2));
+        assert!(entries.iter().all(|entry| entry.room == "tower"));
+
+        let (_, total) = board.page(None, RatingColumn::MaxDepth, true, 0);
+        assert_eq!(total, 5);
+        let (entries, total) = board.page(Some("lobby"), RatingColumn::MaxDepth, true, 0);
+        assert_eq!((entries.len(), total), (0, 0));
+    }
+}
@@ -0,0 +1,94 @@
+// This file keeps the levels the player has left, they come back with the same monsters, items and explored tiles
+
+use std::collections::HashMap;
+
+use serde::{Serialize, Deserialize};
+use specs::prelude::*;
+
+use super::{Map, Position, OtherLevelPosition, Player, Enemy, Viewshed};
+use super::constants::MAPCOUNT;
+
+
+#[derive(Default, Serialize, Deserialize, Clone)]
+/// Maps of the left levels by their depth, the monsters and items of them wait in the world with `OtherLevelPosition`
+pub struct LevelStore {
+    maps : HashMap<i32, Map>,
+}
+
+
+impl LevelStore {
+
+    /// Keep the map of the level the player leaves
+    pub fn store(&mut self, mut map : Map) {
+        map.visible_tiles = vec![false; MAPCOUNT];
+        self.maps.insert(map.depth, map);
+    }
+
+    /// Take out the map of the depth if the player has been there
+    pub fn take(&mut self, depth : i32) -> Option<Map> {
+        let mut map = self.maps.remove(&depth)?;
+        // the contents are not saved with the game
+        map.tile_content = vec![Vec::new(); MAPCOUNT];
+        Some(map)
+    }
+}
+
+
+/// Freeze the monsters and items of the level the player leaves.
+/// The other players are not kept, the game learns their places from their moves
+pub fn freeze_entities(ecs : &mut World, depth : i32) {
+    let frozen = {
+        let entities = ecs.entities();
+        let positions = ecs.read_storage::<Position>();
+        let players = ecs.read_storage::<Player>();
+        let enemies = ecs.read_storage::<Enemy>();
+        (&entities, &positions, !&players, !&enemies).join()
+            .map(|(entity, pos, _, _)| (entity, OtherLevelPosition { x : pos.x, y : pos.y, depth }))
+            .collect::<Vec<_>>()
+    };
+
+    let mut positions = ecs.write_storage::<Position>();
+    let mut other_level_positions = ecs.write_storage::<OtherLevelPosition>();
+    for (entity, other_level_pos) in frozen {
+        positions.remove(entity);
+        other_level_positions.insert(entity, other_level_pos).expect("Unable to freeze entity");
+    }
+}
+
+
+/// Put the frozen monsters and items of the depth back on the level
+pub fn thaw_entities(ecs : &mut World, depth : i32) {
+    let thawed = {
+        let entities = ecs.entities();
+        let other_level_positions = ecs.read_storage::<OtherLevelPosition>();
+        (&entities, &other_level_positions).join()
+            .filter(|(_, other_level_pos)| other_level_pos.depth == depth)
+            .map(|(entity, other_level_pos)| (entity, Position { x : other_level_pos.x, y : other_level_pos.y }))
+            .collect::<Vec<_>>()
+    };
+
+    let mut positions = ecs.write_storage::<Position>();
+    let mut other_level_positions = ecs.write_storage::<OtherLevelPosition>();
+    let mut viewsheds = ecs.write_storage::<Viewshed>();
+    for (entity, pos) in thawed {
+        other_level_positions.remove(entity);
+        positions.insert(entity, pos).expect("Unable to thaw entity");
+        if let Some(viewshed) = viewsheds.get_mut(entity) {
+            viewshed.dirty = true;
+        }
+    }
+}
+
+
+/// Forget all left levels when the world starts over
+pub fn forget_levels(ecs : &mut World) {
+    let frozen = {
+        let entities = ecs.entities();
+        let other_level_positions = ecs.read_storage::<OtherLevelPosition>();
+        (&entities, &other_level_positions).join()
+            .map(|(entity, _)| entity)
+            .collect::<Vec<_>>()
+    };
+    ecs.delete_entities(&frozen).expect("Unable to delete frozen entities");
+    *ecs.write_resource::<LevelStore>() = LevelStore::default();
+}
@@ -0,0 +1,50 @@
+// The game: the shared part that is used by both the client and the server and the client itself,
+// the binaries only start them
+
+pub mod constants;
+pub mod rect;
+pub use rect::Rect;
+pub mod map;
+pub mod protocol;
+pub mod map_codec;
+pub mod leaderboard;
+pub mod validation;
+pub mod accounts;
+pub mod map_builders;
+
+pub use map::*;
+mod player;
+pub use player::*;
+mod components;
+pub use components::*;
+
+mod gui;
+pub use gui::*;
+mod gamelog;
+pub use gamelog::*;
+mod run_stats;
+pub use run_stats::*;
+pub mod spawner;
+pub use spawner::*;
+pub mod level_store;
+mod random_table;
+pub use random_table::*;
+mod client;
+pub use client::*;
+pub mod backends;
+mod config;
+pub use config::*;
+pub use constants::*;
+pub use protocol::*;
+mod state;
+pub use state::*;
+
+pub mod systems;
+pub use systems::damage_system::*;
+pub use systems::enemy_system::*;
+pub use systems::inventory_system::*;
+pub use systems::map_indexing_system::*;
+pub use systems::saveload_system::*;
+pub use systems::visibility_system::*;
+pub use systems::melee_combat_system::*;
+pub use systems::monster_ai_system::*;
@@ -1,9 +1,7 @@
-use rltk::{ RGB, Rltk, RandomNumberGenerator, Point};
+use rltk::{ RGB, Rltk, Point};
 use specs::{World, Entity};
 
-use std::cmp::{max, min};
 use serde::{Serialize, Deserialize};
-use std::fs;
 
 use super::Rect;
 use super::constants::*;
@@ -15,6 +13,7 @@ pub enum TileType {
     Wall, 
     Floor,
     DownStairs,
+    UpStairs,
 }
 
 
@@ -22,6 +21,7 @@ pub enum TileType {
 /// Represents the game map
 pub struct Map {
     pub tiles : Vec<TileType>,
+    /// Rooms of the generators that make them, the caves and mazes have none
     pub rooms : Vec<Rect>,
     pub width : i32,
     pub height : i32,
@@ -38,9 +38,9 @@ pub struct Map {
 
 impl Map {
 
-    /// Create new map, set tunnels and tiles
+    /// Create the level of solid walls, the generators of `map_builders` dig it
     pub fn new(new_depth : i32) -> Self {
-        let mut map = Map {
+        Map {
             tiles : vec![TileType::Wall; MAPCOUNT],
             rooms : Vec::new(),
             width : MAPWIDTH as i32,
@@ -50,78 +50,6 @@ impl Map {
             blocked : vec![false; MAPCOUNT],
             depth : new_depth,
             tile_content : vec![Vec::new(); MAPCOUNT],
-        };
-    
-        let mut rng = RandomNumberGenerator::new();
-    
-        for _ in 0..MAX_ROOMS {
-            let w = rng.range(MIN_SIZE, MAX_SIZE);
-            let h = rng.range(MIN_SIZE, MAX_SIZE);
-            let x = rng.roll_dice(1, MAPWIDTH as i32 - w - 1) - 1;
-            let y = rng.roll_dice(1, MAPHEIGHT as i32 - h - 1) - 1;
-            let new_room = Rect::new(x, y, w, h);
-            let mut ok = true;
-
-            for other_room in map.rooms.iter() {
-                if new_room.intersect(other_room) {
-                    ok = false;
-                }
-            }
-
-            if ok {
-                map.apply_room_to_map(&new_room);
-    
-                if !map.rooms.is_empty() {
-                    let (new_x, new_y) = new_room.center();
-                    let (prev_x, prev_y) = map.rooms[map.rooms.len() - 1].center();
-                    
-                    if rng.range(0,2) == 1 {
-                        map.apply_horizontal_tunnel(prev_x, new_x, prev_y);
-                        map.apply_vertical_tunnel(prev_y, new_y, new_x);
-                    } else {
-                        map.apply_vertical_tunnel( prev_y, new_y, prev_x);
-                        map.apply_horizontal_tunnel(prev_x, new_x, new_y);
-                    }
-                }
-    
-                map.rooms.push(new_room);
-            }
-        }
-
-        let stairs_position = map.rooms[map.rooms.len() - 1].center();
-        let stairs_index = xy_idx(stairs_position.0, stairs_position.1);
-        map.tiles[stairs_index] = TileType::DownStairs;
-
-        map
-    }
-
-    /// Apply given room to map
-    fn apply_room_to_map(&mut self, room : &Rect) {
-        for y in room.y1 + 1 ..= room.y2 {
-            for x in room.x1 + 1 ..= room.x2 {
-                let idx = xy_idx(x, y);
-                self.tiles[idx] = TileType::Floor;
-            }
-        }
-    }
-    
-    /// Apply given horizontal tunnel to map
-    fn apply_horizontal_tunnel(&mut self, x1 : i32, x2 : i32, y : i32) {
-        for x in min(x1, x2) ..= max(x1, x2) {
-            let idx = xy_idx(x, y);
-            if idx > 0 && idx < MAPCOUNT {
-                self.tiles[idx as usize] = TileType::Floor;
-            }
-        }
-    }
-    
-    /// Apply given vertical tunnel to map
-    fn apply_vertical_tunnel(&mut self, y1 : i32, y2 : i32, x : i32) {
-        for y in min(y1, y2) ..= max(y1, y2) {
-            let idx = xy_idx(x, y);
-            if idx > 0 && idx < MAPCOUNT {
-                self.tiles[idx as usize] = TileType::Floor;
-            }
         }
     }
 
@@ -134,6 +62,11 @@ impl Map {
         !self.blocked[idx]
     }
 
+    /// Index of the first tile of the given type, the levels have one tile of each stairs
+    pub fn find_tile(&self, tile : TileType) -> Option<usize> {
+        self.tiles.iter().position(|t| *t == tile)
+    }
+
     /// Fill all wall tiles as blocked
     pub fn populate_blocked(&mut self) {
         for (i, tile) in self.tiles.iter_mut().enumerate() {
@@ -174,7 +107,7 @@ impl rltk::BaseMap for Map {
 
     /// Check is tile a wall
     fn is_opaque(&self, idx : usize) -> bool {
-        self.tiles[idx as usize] == TileType::Wall
+        self.tiles[idx] == TileType::Wall
     }
 
     /// Count path distance from two indexes
@@ -224,19 +157,43 @@ impl rltk::BaseMap for Map {
 }
 
 
-#[derive(Serialize, Deserialize)]
-struct CustomizeTiles {
-    floor : char,
-    wall : char,
-    downstairs : char,
+/// Seed of the level generator for the given depth, so every level is a function of the game seed and the depth
+pub fn level_seed(game_seed : u64, depth : i32) -> u64 {
+    // splitmix64 spreads neighbouring depths far apart
+    let mut z = game_seed.wrapping_add((depth as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+#[serde(default)]
+/// Glyphs of the tiles, can be changed in the config file
+pub struct CustomizeTiles {
+    pub floor : char,
+    pub wall : char,
+    pub downstairs : char,
+    pub upstairs : char,
 }
 
-/// Setting glyph and colour to the floor, wall, downstairs tiles
+
+impl Default for CustomizeTiles {
+    fn default() -> Self {
+        CustomizeTiles {
+            floor : '.',
+            wall : '#',
+            downstairs : '>',
+            upstairs : '<',
+        }
+    }
+}
+
+
+/// Setting glyph and colour to the floor, wall and stairs tiles
 pub fn draw_map(ecs: &World, ctx: &mut Rltk) {
     let map = ecs.fetch::<Map>();
-
-    let file = fs::File::open("customize.txt").expect("Can't create file");
-    let customize : CustomizeTiles = serde_json::from_reader(file).unwrap();
+    let customize = ecs.fetch::<CustomizeTiles>();
 
     let mut y = 0;
     let mut x = 0;
@@ -259,6 +216,10 @@ pub fn draw_map(ecs: &World, ctx: &mut Rltk) {
                     glyph = rltk::to_cp437(customize.downstairs);
                     fg = RGB::from_f32(0., 1., 1.);
                 }
+                TileType::UpStairs => {
+                    glyph = rltk::to_cp437(customize.upstairs);
+                    fg = RGB::from_f32(0., 1., 1.);
+                }
             }
             if !map.visible_tiles[idx] {
                 fg = fg.to_greyscale();
@@ -0,0 +1,67 @@
+use rltk::RandomNumberGenerator;
+
+use crate::map::Map;
+use crate::rect::Rect;
+use crate::constants::*;
+
+use super::{MapBuilder, BuiltLevel};
+use super::common::{apply_room_to_map, join_rooms, level_of_rooms};
+
+
+/// The part of the level smaller than this in both directions isn't split anymore
+const MIN_LEAF_SIZE : i32 = 14;
+
+
+/// Binary space partition: the level is split in halves until the parts are small,
+/// every part gets a room and the neighbouring parts are joined, so the rooms never overlap
+pub struct BspBuilder;
+
+
+impl MapBuilder for BspBuilder {
+
+    fn build(&mut self, depth : i32, rng : &mut RandomNumberGenerator) -> BuiltLevel {
+        let mut map = Map::new(depth);
+
+        let mut leaves = Vec::new();
+        split(Rect::new(1, 1, MAPWIDTH as i32 - 3, MAPHEIGHT as i32 - 3), rng, &mut leaves);
+
+        for leaf in leaves.iter() {
+            let w = rng.range(MIN_SIZE - 2, (leaf.x2 - leaf.x1 - 1).max(MIN_SIZE - 1));
+            let h = rng.range(MIN_SIZE - 2, (leaf.y2 - leaf.y1 - 1).max(MIN_SIZE - 1));
+            let x = leaf.x1 + rng.range(0, (leaf.x2 - leaf.x1 - w).max(1));
+            let y = leaf.y1 + rng.range(0, (leaf.y2 - leaf.y1 - h).max(1));
+            let room = Rect::new(x, y, w, h);
+
+            apply_room_to_map(&mut map, &room);
+            if let Some(prev_room) = map.rooms.last().copied() {
+                join_rooms(&mut map, &prev_room, &room, rng.range(0, 2) == 1);
+            }
+            map.rooms.push(room);
+        }
+
+        level_of_rooms(map)
+    }
+}
+
+
+/// Split the part of the level across its longer side, the leaves go in the order of the tree,
+/// so the neighbouring leaves lie next to each other
+fn split(area : Rect, rng : &mut RandomNumberGenerator, leaves : &mut Vec<Rect>) {
+    let width = area.x2 - area.x1;
+    let height = area.y2 - area.y1;
+
+    if width < MIN_LEAF_SIZE * 2 && height < MIN_LEAF_SIZE * 2 {
+        leaves.push(area);
+        return;
+    }
+
+    if width >= height {
+        let cut = rng.range(MIN_LEAF_SIZE, width - MIN_LEAF_SIZE + 1);
+        split(Rect::new(area.x1, area.y1, cut, height), rng, leaves);
+        split(Rect::new(area.x1 + cut, area.y1, width - cut, height), rng, leaves);
+    } else {
+        let cut = rng.range(MIN_LEAF_SIZE, height - MIN_LEAF_SIZE + 1);
+        split(Rect::new(area.x1, area.y1, width, cut), rng, leaves);
+        split(Rect::new(area.x1, area.y1 + cut, width, height - cut), rng, leaves);
+    }
+}
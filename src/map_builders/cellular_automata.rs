@@ -0,0 +1,52 @@
+use rltk::RandomNumberGenerator;
+
+use crate::map::{Map, TileType, xy_idx};
+
+use super::{MapBuilder, BuiltLevel};
+use super::common::{nearest_floor, level_of_floor};
+
+
+/// Share of the floor in the random noise the caves grow from, in percents
+const FLOOR_CHANCE : i32 = 55;
+
+/// Number of the smoothing passes
+const ITERATIONS : usize = 15;
+
+
+/// Caves: the random noise is smoothed, a tile becomes a wall if most of its neighbours are walls
+pub struct CellularAutomataBuilder;
+
+
+impl MapBuilder for CellularAutomataBuilder {
+
+    fn build(&mut self, depth : i32, rng : &mut RandomNumberGenerator) -> BuiltLevel {
+        let mut map = Map::new(depth);
+
+        for y in 1 .. map.height - 1 {
+            for x in 1 .. map.width - 1 {
+                if rng.roll_dice(1, 100) <= FLOOR_CHANCE {
+                    map.tiles[xy_idx(x, y)] = TileType::Floor;
+                }
+            }
+        }
+
+        for _ in 0..ITERATIONS {
+            let mut tiles = map.tiles.clone();
+            for y in 1 .. map.height - 1 {
+                for x in 1 .. map.width - 1 {
+                    let mut walls = 0;
+                    for (dx, dy) in [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)] {
+                        if map.tiles[xy_idx(x + dx, y + dy)] == TileType::Wall {
+                            walls += 1;
+                        }
+                    }
+                    tiles[xy_idx(x, y)] = if walls > 4 || walls == 0 { TileType::Wall } else { TileType::Floor };
+                }
+            }
+            map.tiles = tiles;
+        }
+
+        let start = nearest_floor(&map, map.width / 2, map.height / 2);
+        level_of_floor(map, start)
+    }
+}
@@ -0,0 +1,165 @@
+// Pieces the generators share: rooms, tunnels, the stairs and the spawn regions
+
+use std::cmp::{max, min};
+
+use crate::map::{Map, TileType, xy_idx};
+use crate::rect::Rect;
+use crate::constants::*;
+
+use super::BuiltLevel;
+
+
+/// Width and height of the square the spawn regions of the caves and mazes are cut by
+const REGION_SIZE : i32 = 12;
+
+
+/// Apply given room to map
+pub fn apply_room_to_map(map : &mut Map, room : &Rect) {
+    for y in room.y1 + 1 ..= room.y2 {
+        for x in room.x1 + 1 ..= room.x2 {
+            let idx = xy_idx(x, y);
+            map.tiles[idx] = TileType::Floor;
+        }
+    }
+}
+
+/// Apply given horizontal tunnel to map
+pub fn apply_horizontal_tunnel(map : &mut Map, x1 : i32, x2 : i32, y : i32) {
+    for x in min(x1, x2) ..= max(x1, x2) {
+        let idx = xy_idx(x, y);
+        if idx > 0 && idx < MAPCOUNT {
+            map.tiles[idx] = TileType::Floor;
+        }
+    }
+}
+
+/// Apply given vertical tunnel to map
+pub fn apply_vertical_tunnel(map : &mut Map, y1 : i32, y2 : i32, x : i32) {
+    for y in min(y1, y2) ..= max(y1, y2) {
+        let idx = xy_idx(x, y);
+        if idx > 0 && idx < MAPCOUNT {
+            map.tiles[idx] = TileType::Floor;
+        }
+    }
+}
+
+/// Join the centers of two rooms with the L-shaped tunnel, the generator chooses the corner
+pub fn join_rooms(map : &mut Map, from : &Rect, to : &Rect, horizontal_first : bool) {
+    let (new_x, new_y) = to.center();
+    let (prev_x, prev_y) = from.center();
+
+    if horizontal_first {
+        apply_horizontal_tunnel(map, prev_x, new_x, prev_y);
+        apply_vertical_tunnel(map, prev_y, new_y, new_x);
+    } else {
+        apply_vertical_tunnel(map, prev_y, new_y, prev_x);
+        apply_horizontal_tunnel(map, prev_x, new_x, new_y);
+    }
+}
+
+/// The level made of rooms: the players start in the first room, the stairs are in the center of the last one,
+/// every other room is a spawn region
+pub fn level_of_rooms(mut map : Map) -> BuiltLevel {
+    let start = map.rooms[0].center();
+    let stairs = map.rooms[map.rooms.len() - 1].center();
+    map.tiles[xy_idx(stairs.0, stairs.1)] = TileType::DownStairs;
+
+    let spawn_regions = map.rooms.iter()
+        .skip(1)
+        .map(|room| {
+            let mut region = Vec::new();
+            for y in room.y1 + 1 ..= room.y2 {
+                for x in room.x1 + 1 ..= room.x2 {
+                    region.push(xy_idx(x, y));
+                }
+            }
+            region
+        })
+        .collect();
+
+    BuiltLevel { map, start, spawn_regions, spawns : Vec::new() }
+}
+
+/// Distances from the start to every tile, the walls and the unreachable tiles are at `f32::MAX`
+fn distances_from(map : &mut Map, start : (i32, i32)) -> rltk::DijkstraMap {
+    map.populate_blocked();
+    rltk::DijkstraMap::new(map.width, map.height, &[xy_idx(start.0, start.1)], map, MAPCOUNT as f32)
+}
+
+/// Check if every tile that is not a wall can be reached from the start
+pub fn is_connected(map : &mut Map, start : (i32, i32)) -> bool {
+    let distances = distances_from(map, start);
+    map.tiles.iter()
+        .zip(distances.map.iter())
+        .all(|(tile, distance)| *tile == TileType::Wall || *distance < f32::MAX)
+}
+
+/// The floor tile that is the farthest to walk from the start, nothing if no floor but the start can be reached
+pub fn farthest_floor(map : &mut Map, start : (i32, i32)) -> Option<usize> {
+    let start_idx = xy_idx(start.0, start.1);
+    let distances = distances_from(map, start);
+    map.tiles.iter()
+        .zip(distances.map.iter())
+        .enumerate()
+        .filter(|(idx, (tile, distance))| *idx != start_idx && **tile == TileType::Floor && **distance < f32::MAX)
+        .max_by(|(_, (_, a)), (_, (_, b))| a.total_cmp(b))
+        .map(|(idx, _)| idx)
+}
+
+/// The floor tile nearest to the given point
+pub fn nearest_floor(map : &Map, x : i32, y : i32) -> (i32, i32) {
+    let mut best = (x, y);
+    let mut best_distance = i32::MAX;
+    for (idx, tile) in map.tiles.iter().enumerate() {
+        if *tile != TileType::Floor {
+            continue;
+        }
+        let (tile_x, tile_y) = (idx as i32 % map.width, idx as i32 / map.width);
+        let distance = (tile_x - x).pow(2) + (tile_y - y).pow(2);
+        if distance < best_distance {
+            best_distance = distance;
+            best = (tile_x, tile_y);
+        }
+    }
+    best
+}
+
+/// The level without rooms: the floor the players can't reach from the start is filled in,
+/// the stairs go to the farthest tile, the floor is cut into square spawn regions
+pub fn level_of_floor(mut map : Map, start : (i32, i32)) -> BuiltLevel {
+    let start_idx = xy_idx(start.0, start.1);
+    let distances = distances_from(&mut map, start);
+
+    let mut stairs = start_idx;
+    let mut farthest = 0.0;
+    for (idx, distance) in distances.map.iter().enumerate() {
+        if map.tiles[idx] != TileType::Floor {
+            continue;
+        }
+        if *distance == f32::MAX {
+            map.tiles[idx] = TileType::Wall;
+        } else if *distance > farthest {
+            farthest = *distance;
+            stairs = idx;
+        }
+    }
+    map.tiles[stairs] = TileType::DownStairs;
+
+    let columns = (map.width + REGION_SIZE - 1) / REGION_SIZE;
+    let rows = (map.height + REGION_SIZE - 1) / REGION_SIZE;
+    let start_region = (start.1 / REGION_SIZE) * columns + start.0 / REGION_SIZE;
+    let mut spawn_regions = vec![Vec::new(); (columns * rows) as usize];
+    for (idx, tile) in map.tiles.iter().enumerate() {
+        if *tile != TileType::Floor {
+            continue;
+        }
+        let (x, y) = (idx as i32 % map.width, idx as i32 / map.width);
+        let region = (y / REGION_SIZE) * columns + x / REGION_SIZE;
+        if region != start_region {
+            spawn_regions[region as usize].push(idx);
+        }
+    }
+    spawn_regions.retain(|region| !region.is_empty());
+
+    BuiltLevel { map, start, spawn_regions, spawns : Vec::new() }
+}
@@ -0,0 +1,55 @@
+use rltk::RandomNumberGenerator;
+
+use crate::map::{Map, TileType, xy_idx};
+
+use super::{MapBuilder, BuiltLevel};
+use super::common::level_of_floor;
+
+
+/// The walk stops when this share of the level is floor, in percents
+const FLOOR_PERCENT : usize = 45;
+
+/// Steps of one digger before the next one starts
+const DIGGER_LIFETIME : i32 = 400;
+
+
+/// Drunkard's walk: diggers start from the already dug floor and stagger around until they are tired,
+/// the level becomes a winding cave
+pub struct DrunkardsWalkBuilder;
+
+
+impl MapBuilder for DrunkardsWalkBuilder {
+
+    fn build(&mut self, depth : i32, rng : &mut RandomNumberGenerator) -> BuiltLevel {
+        let mut map = Map::new(depth);
+        let start = (map.width / 2, map.height / 2);
+        map.tiles[xy_idx(start.0, start.1)] = TileType::Floor;
+
+        let wanted = map.tiles.len() * FLOOR_PERCENT / 100;
+        let mut floor = vec![xy_idx(start.0, start.1)];
+
+        while floor.len() < wanted {
+            // the first digger starts at the start, the others anywhere on the floor
+            let from = floor[rng.roll_dice(1, floor.len() as i32) as usize - 1];
+            let (mut x, mut y) = (from as i32 % map.width, from as i32 / map.width);
+
+            for _ in 0..DIGGER_LIFETIME {
+                let idx = xy_idx(x, y);
+                if map.tiles[idx] == TileType::Wall {
+                    map.tiles[idx] = TileType::Floor;
+                    floor.push(idx);
+                }
+
+                match rng.roll_dice(1, 4) {
+                    1 if x > 2 => x -= 1,
+                    2 if x < map.width - 3 => x += 1,
+                    3 if y > 2 => y -= 1,
+                    4 if y < map.height - 3 => y += 1,
+                    _ => {}
+                }
+            }
+        }
+
+        level_of_floor(map, start)
+    }
+}
@@ -0,0 +1,66 @@
+use rltk::RandomNumberGenerator;
+
+use crate::map::{Map, TileType, xy_idx};
+
+use super::{MapBuilder, BuiltLevel};
+use super::common::level_of_floor;
+
+
+/// Chance to break one more wall between two cells, in percents. The loops give the way around the monsters
+const LOOP_CHANCE : i32 = 8;
+
+
+/// Maze of one tile wide corridors dug by the recursive backtracker, with a few loops
+pub struct MazeBuilder;
+
+
+impl MapBuilder for MazeBuilder {
+
+    fn build(&mut self, depth : i32, rng : &mut RandomNumberGenerator) -> BuiltLevel {
+        let mut map = Map::new(depth);
+
+        // cells are on the odd tiles, the walls between them on the even ones
+        let columns = (map.width - 1) / 2;
+        let rows = (map.height - 1) / 2;
+        let cell_tile = |column : i32, row : i32| (column * 2 + 1, row * 2 + 1);
+
+        let mut visited = vec![false; (columns * rows) as usize];
+        let mut stack = vec![(0, 0)];
+        visited[0] = true;
+        let (x, y) = cell_tile(0, 0);
+        map.tiles[xy_idx(x, y)] = TileType::Floor;
+
+        while let Some(&(column, row)) = stack.last() {
+            let neighbours = [(0, -1), (1, 0), (0, 1), (-1, 0)].iter()
+                .map(|(dx, dy)| (column + dx, row + dy))
+                .filter(|(c, r)| *c >= 0 && *c < columns && *r >= 0 && *r < rows && !visited[(r * columns + c) as usize])
+                .collect::<Vec<_>>();
+
+            if neighbours.is_empty() {
+                stack.pop();
+                continue;
+            }
+
+            let (next_column, next_row) = neighbours[rng.roll_dice(1, neighbours.len() as i32) as usize - 1];
+            visited[(next_row * columns + next_column) as usize] = true;
+
+            let (from_x, from_y) = cell_tile(column, row);
+            let (to_x, to_y) = cell_tile(next_column, next_row);
+            map.tiles[xy_idx((from_x + to_x) / 2, (from_y + to_y) / 2)] = TileType::Floor;
+            map.tiles[xy_idx(to_x, to_y)] = TileType::Floor;
+            stack.push((next_column, next_row));
+        }
+
+        // a few walls between the cells are broken, the dead ends become loops
+        for y in 1 .. map.height - 1 {
+            for x in 1 .. map.width - 1 {
+                let between_cells = (x % 2 == 0) != (y % 2 == 0);
+                if between_cells && rng.roll_dice(1, 100) <= LOOP_CHANCE {
+                    map.tiles[xy_idx(x, y)] = TileType::Floor;
+                }
+            }
+        }
+
+        level_of_floor(map, cell_tile(0, 0))
+    }
+}
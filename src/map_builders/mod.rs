@@ -0,0 +1,92 @@
+// Submodule input file
+// Every depth is built by one of the generators below. The generator is chosen with the level's own random
+// generator, so the games and the server build the same level from the same seed
+
+use rltk::RandomNumberGenerator;
+
+use super::map::{Map, TileType, xy_idx};
+
+mod common;
+pub mod simple_map;
+pub mod bsp;
+pub mod cellular_automata;
+pub mod drunkard;
+pub mod maze;
+pub mod prefabs;
+
+use simple_map::SimpleMapBuilder;
+use bsp::BspBuilder;
+use cellular_automata::CellularAutomataBuilder;
+use drunkard::DrunkardsWalkBuilder;
+use maze::MazeBuilder;
+use prefabs::Prefab;
+
+
+/// Level built by the generator
+pub struct BuiltLevel {
+    pub map : Map,
+    /// Tile the players come down to the depth on, the up stairs are there below the first depth
+    pub start : (i32, i32),
+    /// Groups of the floor tiles the monsters and items are spawned in, the start is never among them
+    pub spawn_regions : Vec<Vec<usize>>,
+    /// Monsters and items the prefabs have put on their tiles
+    pub spawns : Vec<(usize, String)>,
+}
+
+
+impl BuiltLevel {
+
+    /// Index of the start tile
+    pub fn start_idx(&self) -> i32 {
+        xy_idx(self.start.0, self.start.1) as i32
+    }
+}
+
+
+/// Generator of the levels
+pub trait MapBuilder {
+
+    /// Build the level of the given depth, the same generator state always gives the same level
+    fn build(&mut self, depth : i32, rng : &mut RandomNumberGenerator) -> BuiltLevel;
+}
+
+
+/// Generator of the given depth: the first level is always made of rooms, the deeper ones differ
+pub fn builder_for(depth : i32, rng : &mut RandomNumberGenerator) -> Box<dyn MapBuilder> {
+    if depth <= 1 {
+        return Box::new(SimpleMapBuilder);
+    }
+    match rng.roll_dice(1, 5) {
+        1 => Box::new(SimpleMapBuilder),
+        2 => Box::new(BspBuilder),
+        3 => Box::new(CellularAutomataBuilder),
+        4 => Box::new(DrunkardsWalkBuilder),
+        _ => Box::new(MazeBuilder),
+    }
+}
+
+
+/// Build the level of the depth with its generator and stamp the prefabs into it,
+/// the players come down to the up stairs on the start tile.
+/// Every side has to use the same prefabs, otherwise their levels differ
+pub fn build_level(depth : i32, rng : &mut RandomNumberGenerator, prefabs : &[Prefab]) -> BuiltLevel {
+    loop {
+        let mut level = builder_for(depth, rng).build(depth, rng);
+        prefabs::stamp_prefabs(&mut level, depth, rng, prefabs);
+        if depth <= 1 {
+            return level;
+        }
+
+        // the level of one room has the down stairs on the start, they move away before the up stairs take the tile
+        let start = level.start_idx() as usize;
+        if level.map.tiles[start] == TileType::DownStairs {
+            match common::farthest_floor(&mut level.map, level.start) {
+                Some(stairs) => level.map.tiles[stairs] = TileType::DownStairs,
+                // nothing can be reached from the start, the next level of the same generator state is taken
+                None => continue,
+            }
+        }
+        level.map.tiles[start] = TileType::UpStairs;
+        return level;
+    }
+}
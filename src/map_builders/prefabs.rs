@@ -0,0 +1,419 @@
+// Hand-made rooms and vaults that are stamped into the generated levels. They are read from the text files,
+// so the set pieces are added without changing the game:
+//
+//     name = Treasure vault
+//     depth = 3-
+//     rarity = 4
+//     place = anywhere
+//     o = Orc
+//     ! = Health Potion
+//
+//     #######
+//     #.!.!.#
+//     #..o..#
+//     ###.###
+//
+// The header ends with the empty line. `depth` is one depth, a range or a range without the end,
+// `rarity` 4 means one level of four gets the prefab, `place` is `room` or `anywhere`.
+// The one-letter keys are the legend: the monster or the item of the letter stands on the floor,
+// the names are the ones the spawner knows.
+// In the picture `#` is the wall, `.` is the floor and the space keeps the tile of the generator
+
+use std::fs;
+use std::path::Path;
+
+use rltk::RandomNumberGenerator;
+
+use crate::map::{TileType, xy_idx, idx_xy};
+use crate::rect::Rect;
+use crate::spawner;
+
+use super::BuiltLevel;
+use super::common::is_connected;
+
+
+/// Folder the game and the server read the prefabs from
+pub const PREFAB_DIR : &str = "./assets/prefabs";
+
+/// The most prefabs on one level
+const MAX_PREFABS : usize = 3;
+
+/// Number of the spots tried for one prefab before it is given up
+const PLACEMENT_TRIES : usize = 20;
+
+
+#[derive(Clone, Debug, PartialEq)]
+/// One character of the prefab picture
+enum Cell {
+    /// The tile of the generator stays
+    Keep,
+    Wall,
+    Floor,
+    /// The floor with the named monster or item
+    Spawn(String),
+}
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Where the prefab may be stamped
+pub enum Placement {
+    /// Inside the room that is big enough, the levels without rooms don't get it
+    Room,
+    /// Anywhere on the level
+    Anywhere,
+}
+
+
+#[derive(Clone, Debug, PartialEq)]
+/// Hand-made piece of the level
+pub struct Prefab {
+    pub name : String,
+    pub min_depth : i32,
+    /// The deepest level of the prefab, there is no limit without it
+    pub max_depth : Option<i32>,
+    pub rarity : i32,
+    pub placement : Placement,
+    width : i32,
+    height : i32,
+    cells : Vec<Cell>,
+}
+
+
+impl Prefab {
+
+    /// Read the prefab from the text, the name of the file is used when the prefab has no name
+    pub fn parse(file_name : &str, data : &str) -> Result<Self, String> {
+        let mut prefab = Prefab {
+            name : file_name.to_string(),
+            min_depth : 1,
+            max_depth : None,
+            rarity : 1,
+            placement : Placement::Anywhere,
+            width : 0,
+            height : 0,
+            cells : Vec::new(),
+        };
+
+        let mut lines = data.lines();
+        let mut legend : Vec<(char, String)> = Vec::new();
+        for line in lines.by_ref() {
+            if line.trim().is_empty() {
+                break;
+            }
+            let (key, value) = line.split_once('=').ok_or_else(|| format!("Expected `key = value`, got `{}`", line))?;
+            let (key, value) = (key.trim(), value.trim());
+            match key {
+                "name" => prefab.name = value.to_string(),
+                "depth" => {
+                    let (min, max) = parse_depth(value).ok_or_else(|| format!("Wrong depth `{}`", value))?;
+                    prefab.min_depth = min;
+                    prefab.max_depth = max;
+                }
+                "rarity" => {
+                    prefab.rarity = value.parse().ok()
+                        .filter(|rarity| *rarity > 0)
+                        .ok_or_else(|| format!("Wrong rarity `{}`", value))?;
+                }
+                "place" => {
+                    prefab.placement = match value {
+                        "room" => Placement::Room,
+                        "anywhere" => Placement::Anywhere,
+                        _ => return Err(format!("Wrong place `{}`, it is `room` or `anywhere`", value)),
+                    };
+                }
+                _ => {
+                    let mut chars = key.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) if !matches!(c, '#' | '.') => {
+                            if !spawner::is_spawnable(value) {
+                                return Err(format!("Unknown monster or item `{}`", value));
+                            }
+                            legend.push((c, value.to_string()));
+                        }
+                        _ => return Err(format!("Unknown key `{}`", key)),
+                    }
+                }
+            }
+        }
+
+        let rows : Vec<&str> = lines.collect();
+        let rows = &rows[..rows.iter().rposition(|row| !row.trim().is_empty()).map_or(0, |last| last + 1)];
+        prefab.width = rows.iter().map(|row| row.chars().count()).max().unwrap_or(0) as i32;
+        prefab.height = rows.len() as i32;
+        if prefab.width == 0 {
+            return Err("The picture is empty".to_string());
+        }
+
+        for row in rows {
+            let mut chars = row.chars();
+            for _ in 0..prefab.width {
+                let cell = match chars.next() {
+                    None | Some(' ') => Cell::Keep,
+                    Some('#') => Cell::Wall,
+                    Some('.') => Cell::Floor,
+                    Some(c) => match legend.iter().find(|(letter, _)| *letter == c) {
+                        Some((_, name)) => Cell::Spawn(name.clone()),
+                        None => return Err(format!("`{}` is not in the legend", c)),
+                    },
+                };
+                prefab.cells.push(cell);
+            }
+        }
+        Ok(prefab)
+    }
+
+    /// Check if the prefab may appear on the depth
+    pub fn fits_depth(&self, depth : i32) -> bool {
+        depth >= self.min_depth && self.max_depth.is_none_or(|max| depth <= max)
+    }
+
+    /// Top left corner of the random spot for the prefab, `None` if it fits nowhere
+    fn choose_spot(&self, level : &BuiltLevel, rng : &mut RandomNumberGenerator) -> Option<(i32, i32)> {
+        match self.placement {
+            Placement::Room => {
+                // the players start in the first room and the stairs are in the last one
+                let rooms : Vec<&Rect> = level.map.rooms.iter()
+                    .skip(1)
+                    .take(level.map.rooms.len().saturating_sub(2))
+                    .filter(|room| room.x2 - room.x1 >= self.width && room.y2 - room.y1 >= self.height)
+                    .collect();
+                if rooms.is_empty() {
+                    return None;
+                }
+                let room = rooms[rng.roll_dice(1, rooms.len() as i32) as usize - 1];
+                Some((room.x1 + 1 + rng.range(0, room.x2 - room.x1 - self.width + 1),
+                    room.y1 + 1 + rng.range(0, room.y2 - room.y1 - self.height + 1)))
+            }
+            Placement::Anywhere => {
+                if self.width > level.map.width - 2 || self.height > level.map.height - 2 {
+                    return None;
+                }
+                Some((1 + rng.range(0, level.map.width - 1 - self.width),
+                    1 + rng.range(0, level.map.height - 1 - self.height)))
+            }
+        }
+    }
+
+    /// Stamp the prefab with the top left corner on the given tile.
+    /// It is taken back if some floor of the level can't be reached from the start then
+    fn stamp(&self, level : &mut BuiltLevel, x : i32, y : i32) -> bool {
+        let old_tiles = level.map.tiles.clone();
+        let mut spawns = Vec::new();
+        for (i, cell) in self.cells.iter().enumerate() {
+            let idx = xy_idx(x + i as i32 % self.width, y + i as i32 / self.width);
+            match cell {
+                Cell::Keep => {}
+                Cell::Wall => level.map.tiles[idx] = TileType::Wall,
+                Cell::Floor => level.map.tiles[idx] = TileType::Floor,
+                Cell::Spawn(name) => {
+                    level.map.tiles[idx] = TileType::Floor;
+                    spawns.push((idx, name.clone()));
+                }
+            }
+        }
+
+        if !is_connected(&mut level.map, level.start) {
+            level.map.tiles = old_tiles;
+            return false;
+        }
+        level.spawns.extend(spawns);
+        true
+    }
+}
+
+
+/// Depth like `3`, `2-5` or `4-`
+fn parse_depth(value : &str) -> Option<(i32, Option<i32>)> {
+    match value.split_once('-') {
+        None => {
+            let depth = value.parse().ok()?;
+            Some((depth, Some(depth)))
+        }
+        Some((min, "")) => Some((min.trim().parse().ok()?, None)),
+        Some((min, max)) => Some((min.trim().parse().ok()?, Some(max.trim().parse().ok()?))),
+    }
+}
+
+
+/// Check if the tile is inside the area, the borders included
+fn covers(area : &Rect, idx : usize) -> bool {
+    let (x, y) = idx_xy(idx as i32);
+    x >= area.x1 && x <= area.x2 && y >= area.y1 && y <= area.y2
+}
+
+
+/// Read all `.txt` prefabs of the folder in the order of their file names,
+/// there are none if the folder doesn't exist
+pub fn load_prefabs(dir : &Path) -> Result<Vec<Prefab>, String> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut paths : Vec<_> = fs::read_dir(dir)
+        .map_err(|e| format!("Can't read prefabs {}: {}", dir.display(), e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .collect();
+    paths.sort();
+
+    paths.iter()
+        .map(|path| {
+            let data = fs::read_to_string(path).map_err(|e| format!("Can't read prefab {}: {}", path.display(), e))?;
+            let file_name = path.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default();
+            Prefab::parse(&file_name, &data).map_err(|e| format!("Can't parse prefab {}: {}", path.display(), e))
+        })
+        .collect()
+}
+
+
+/// Hash of the prefabs, the sides with the same hash build the same levels from the same seed.
+/// It is FNV-1a, so it doesn't change between the builds
+pub fn prefab_hash(prefabs : &[Prefab]) -> u64 {
+    let mut data = String::new();
+    for prefab in prefabs.iter() {
+        let placement = match prefab.placement {
+            Placement::Room => "room",
+            Placement::Anywhere => "anywhere",
+        };
+        data += &format!("{}\n{}\n{:?}\n{}\n{}\n{}x{}\n", prefab.name, prefab.min_depth, prefab.max_depth,
+            prefab.rarity, placement, prefab.width, prefab.height);
+        for cell in prefab.cells.iter() {
+            match cell {
+                Cell::Keep => data.push(' '),
+                Cell::Wall => data.push('#'),
+                Cell::Floor => data.push('.'),
+                Cell::Spawn(name) => data += &format!("[{}]", name),
+            }
+        }
+        data.push('\n');
+    }
+
+    data.bytes().fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+
+/// Stamp the prefabs of the depth into the level, each one with the chance of its rarity.
+/// The random spawns under the prefabs are dropped
+pub fn stamp_prefabs(level : &mut BuiltLevel, depth : i32, rng : &mut RandomNumberGenerator, prefabs : &[Prefab]) {
+    let stairs = level.map.find_tile(TileType::DownStairs);
+    let mut stamped : Vec<Rect> = Vec::new();
+
+    for prefab in prefabs.iter().filter(|prefab| prefab.fits_depth(depth)) {
+        if stamped.len() >= MAX_PREFABS {
+            break;
+        }
+        if rng.roll_dice(1, prefab.rarity) != 1 {
+            continue;
+        }
+
+        for _ in 0..PLACEMENT_TRIES {
+            let Some((x, y)) = prefab.choose_spot(level, rng) else {
+                break;
+            };
+            let area = Rect::new(x, y, prefab.width - 1, prefab.height - 1);
+            if stamped.iter().any(|other| area.intersect(other))
+                || covers(&area, level.start_idx() as usize)
+                || stairs.is_some_and(|stairs| covers(&area, stairs)) {
+                continue;
+            }
+            if prefab.stamp(level, x, y) {
+                stamped.push(area);
+                break;
+            }
+        }
+    }
+
+    for region in level.spawn_regions.iter_mut() {
+        region.retain(|idx| !stamped.iter().any(|area| covers(area, *idx)));
+    }
+    level.spawn_regions.retain(|region| !region.is_empty());
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map_builders::build_level;
+
+    const VAULT : &str = "name = Vault\ndepth = 2-5\nrarity = 2\nplace = room\no = Orc\n\n#####\n#.o.#\n## ##\n";
+
+    /// Closed ring of walls, the floor inside can't be reached from anywhere
+    const RING : &str = "name = Ring\n\n#####\n#...#\n#####\n";
+
+    /// The hash of the vault in every build
+    const HASH_OF_VAULT : u64 = 0xe649ba6a7e0afd57;
+
+    #[test]
+    /// The picture uses only the letters of the legend, the legend names only what the spawner knows
+    fn legend_errors() {
+        assert_eq!(Prefab::parse("p", "\n.x.\n"), Err("`x` is not in the legend".to_string()));
+        assert_eq!(Prefab::parse("p", "x = Dragon\n\n.x.\n"), Err("Unknown monster or item `Dragon`".to_string()));
+        assert_eq!(Prefab::parse("p", "# = Orc\n\n.#.\n"), Err("Unknown key `#`".to_string()));
+        assert_eq!(Prefab::parse("p", "xy = Orc\n\n...\n"), Err("Unknown key `xy`".to_string()));
+        assert_eq!(Prefab::parse("p", "x Orc\n\n.x.\n"), Err("Expected `key = value`, got `x Orc`".to_string()));
+        assert_eq!(Prefab::parse("p", "x = Orc\n\n\n"), Err("The picture is empty".to_string()));
+    }
+
+    #[test]
+    /// The letter of the legend is the floor with its monster, the space keeps the tile
+    fn legend_spawns_on_the_floor() {
+        let prefab = Prefab::parse("vault", VAULT).expect("Can't parse prefab");
+        assert_eq!((prefab.name.as_str(), prefab.width, prefab.height), ("Vault", 5, 3));
+        assert_eq!(prefab.cells[7], Cell::Spawn("Orc".to_string()));
+        assert_eq!(prefab.cells[12], Cell::Keep);
+        assert_eq!((prefab.rarity, prefab.placement), (2, Placement::Room));
+    }
+
+    #[test]
+    /// One depth, the range and the range without the end
+    fn depth_ranges() {
+        assert_eq!(parse_depth("3"), Some((3, Some(3))));
+        assert_eq!(parse_depth("2-5"), Some((2, Some(5))));
+        assert_eq!(parse_depth("4-"), Some((4, None)));
+        assert_eq!(parse_depth("-4"), None);
+        assert_eq!(parse_depth("deep"), None);
+        assert_eq!(Prefab::parse("p", "depth = 2-x\n\n.\n"), Err("Wrong depth `2-x`".to_string()));
+
+        let fits = |depth : &str| {
+            let prefab = Prefab::parse("p", &format!("depth = {}\n\n.\n", depth)).expect("Can't parse prefab");
+            (1..=8).filter(|depth| prefab.fits_depth(*depth)).collect::<Vec<_>>()
+        };
+        assert_eq!(fits("3"), vec![3]);
+        assert_eq!(fits("2-5"), vec![2, 3, 4, 5]);
+        assert_eq!(fits("4-"), vec![4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    /// The hash is the same in every build and changes with every prefab
+    fn hash_is_stable() {
+        let vault = Prefab::parse("vault", VAULT).expect("Can't parse prefab");
+        let ring = Prefab::parse("ring", RING).expect("Can't parse prefab");
+
+        // the games of other builds have to agree on the same number
+        assert_eq!(prefab_hash(&[]), 0xcbf29ce484222325);
+        assert_eq!(prefab_hash(&[vault.clone(), ring.clone()]), prefab_hash(&[vault.clone(), ring.clone()]));
+        assert_eq!(prefab_hash(std::slice::from_ref(&vault)), HASH_OF_VAULT);
+
+        // every change of the prefabs or their order is another hash
+        let moved = Prefab::parse("vault", &VAULT.replace("#.o.#", "#..o#")).expect("Can't parse prefab");
+        let deeper = Prefab::parse("vault", &VAULT.replace("2-5", "2-6")).expect("Can't parse prefab");
+        assert_ne!(prefab_hash(&[moved]), HASH_OF_VAULT);
+        assert_ne!(prefab_hash(&[deeper]), HASH_OF_VAULT);
+        assert_ne!(prefab_hash(&[vault.clone(), ring.clone()]), prefab_hash(&[ring, vault]));
+    }
+
+    #[test]
+    /// The prefab that cuts off a part of the level is taken back
+    fn disconnecting_stamp_is_rejected() {
+        let ring = Prefab::parse("ring", RING).expect("Can't parse prefab");
+        for seed in 0..20 {
+            let mut rng = RandomNumberGenerator::seeded(seed);
+            let mut level = build_level(1, &mut rng, &[]);
+            let tiles = level.map.tiles.clone();
+
+            assert!(!ring.stamp(&mut level, 10, 10), "The ring is stamped, seed {}", seed);
+            stamp_prefabs(&mut level, 1, &mut rng, std::slice::from_ref(&ring));
+            assert!(level.map.tiles == tiles, "The level has changed, seed {}", seed);
+            assert!(level.spawns.is_empty());
+        }
+    }
+}
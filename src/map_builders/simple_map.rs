@@ -0,0 +1,40 @@
+use rltk::RandomNumberGenerator;
+
+use crate::map::Map;
+use crate::rect::Rect;
+use crate::constants::*;
+
+use super::{MapBuilder, BuiltLevel};
+use super::common::{apply_room_to_map, join_rooms, level_of_rooms};
+
+
+/// Random non-overlapping rooms joined one after another by L-shaped tunnels
+pub struct SimpleMapBuilder;
+
+
+impl MapBuilder for SimpleMapBuilder {
+
+    fn build(&mut self, depth : i32, rng : &mut RandomNumberGenerator) -> BuiltLevel {
+        let mut map = Map::new(depth);
+
+        for _ in 0..MAX_ROOMS {
+            let w = rng.range(MIN_SIZE, MAX_SIZE);
+            let h = rng.range(MIN_SIZE, MAX_SIZE);
+            let x = rng.roll_dice(1, MAPWIDTH as i32 - w - 1) - 1;
+            let y = rng.roll_dice(1, MAPHEIGHT as i32 - h - 1) - 1;
+            let new_room = Rect::new(x, y, w, h);
+
+            if map.rooms.iter().any(|other_room| new_room.intersect(other_room)) {
+                continue;
+            }
+
+            apply_room_to_map(&mut map, &new_room);
+            if let Some(prev_room) = map.rooms.last().copied() {
+                join_rooms(&mut map, &prev_room, &new_room, rng.range(0, 2) == 1);
+            }
+            map.rooms.push(new_room);
+        }
+
+        level_of_rooms(map)
+    }
+}
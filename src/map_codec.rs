@@ -0,0 +1,124 @@
+// Encodings of the levels that are transferred between the games and the server.
+// The levels are built from the seed, only their shared entities go over the network in `__WORLD__`,
+// the packed string tells its encoding by the prefix
+
+use std::io::{Read, Write};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use flate2::Compression;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+
+use crate::protocol::{ProtocolMessage, SharedEntity};
+
+
+/// Capability of the MessagePack levels
+pub const MSGPACK_MAPS : &str = "msgpack_maps";
+
+/// Capability of the compressed MessagePack levels
+pub const COMPRESSED_MAPS : &str = "compressed_maps";
+
+const MSGPACK_PREFIX : &str = "msgpack:";
+const COMPRESSED_PREFIX : &str = "msgpack+deflate:";
+
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+/// Format of the shared entities of the level
+pub enum MapEncoding {
+    /// Plain json in `entities`, is understood by every game
+    Json,
+    /// MessagePack in base64
+    MessagePack,
+    /// Deflated MessagePack in base64
+    Compressed,
+}
+
+
+impl MapEncoding {
+
+    /// The most compact encoding both sides of the connection support
+    pub fn negotiate(capabilities : &[String]) -> Self {
+        if capabilities.iter().any(|c| c == COMPRESSED_MAPS) {
+            MapEncoding::Compressed
+        } else if capabilities.iter().any(|c| c == MSGPACK_MAPS) {
+            MapEncoding::MessagePack
+        } else {
+            MapEncoding::Json
+        }
+    }
+
+    /// Find out the encoding of the packed entities
+    pub fn detect(packed : &str) -> Self {
+        if packed.starts_with(COMPRESSED_PREFIX) {
+            MapEncoding::Compressed
+        } else if packed.starts_with(MSGPACK_PREFIX) {
+            MapEncoding::MessagePack
+        } else {
+            MapEncoding::Json
+        }
+    }
+}
+
+
+/// Pack the entities into the string of the given encoding, everything stays in memory
+pub fn pack(entities : &[SharedEntity], encoding : MapEncoding) -> String {
+    match encoding {
+        MapEncoding::Json => serde_json::to_string(entities).expect("Can't serialize entities"),
+        MapEncoding::MessagePack => format!("{}{}", MSGPACK_PREFIX, BASE64.encode(to_msgpack(entities))),
+        MapEncoding::Compressed => {
+            let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+            encoder.write_all(&to_msgpack(entities)).expect("Can't compress entities");
+            let compressed = encoder.finish().expect("Can't compress entities");
+            format!("{}{}", COMPRESSED_PREFIX, BASE64.encode(compressed))
+        }
+    }
+}
+
+
+/// Unpack the entities of any encoding
+pub fn unpack(packed : &str) -> Result<Vec<SharedEntity>, String> {
+    match MapEncoding::detect(packed) {
+        MapEncoding::Json => serde_json::from_str(packed).map_err(|e| e.to_string()),
+        MapEncoding::MessagePack => {
+            let data = BASE64.decode(&packed[MSGPACK_PREFIX.len()..]).map_err(|e| e.to_string())?;
+            rmp_serde::from_slice(&data).map_err(|e| e.to_string())
+        }
+        MapEncoding::Compressed => {
+            let compressed = BASE64.decode(&packed[COMPRESSED_PREFIX.len()..]).map_err(|e| e.to_string())?;
+            let mut data = Vec::new();
+            DeflateDecoder::new(&compressed[..]).read_to_end(&mut data).map_err(|e| e.to_string())?;
+            rmp_serde::from_slice(&data).map_err(|e| e.to_string())
+        }
+    }
+}
+
+
+/// Move the entities of the `__WORLD__` message into the given encoding, the other messages stay as they are
+pub fn pack_world(msg : ProtocolMessage, encoding : MapEncoding) -> ProtocolMessage {
+    match msg {
+        ProtocolMessage::World { depth, entities, stored, packed : None } if encoding != MapEncoding::Json => {
+            let packed = Some(pack(&entities, encoding));
+            ProtocolMessage::World { depth, entities : Vec::new(), stored, packed }
+        }
+        msg => msg,
+    }
+}
+
+
+/// Move the packed entities of the `__WORLD__` message back to the plain `entities`
+pub fn unpack_world(msg : ProtocolMessage) -> Result<ProtocolMessage, String> {
+    match msg {
+        ProtocolMessage::World { depth, stored, packed : Some(packed), .. } => {
+            let entities = unpack(&packed)?;
+            Ok(ProtocolMessage::World { depth, entities, stored, packed : None })
+        }
+        msg => Ok(msg),
+    }
+}
+
+
+/// Entities in MessagePack, the fields are kept by name so the older games can still read the newer entities
+fn to_msgpack(entities : &[SharedEntity]) -> Vec<u8> {
+    rmp_serde::to_vec_named(entities).expect("Can't serialize entities")
+}
@@ -1,28 +1,62 @@
 use rltk::{VirtualKeyCode, Rltk, Point};
 use specs::prelude::*;
 
-use std::cmp::{min, max};
+use super::{Position, Player, TileType, State, Viewshed, Map, Item, WantsToPickupItem, ClientHandler, ProtocolMessage};
+use super::{xy_idx, RunState, CombatStats, WantsToMelee, GameLog, Monster, Enemy, Name, GameMode};
 
-use super::{Position, Player, TileType, State, Viewshed, Map, Item, WantsToPickupItem, ClientHandler};
-use super::{xy_idx, RunState, CombatStats, WantsToMelee, GameLog, Monster};
+
+#[derive(Default, Clone, Copy, Debug)]
+/// Number of the last move the player has sent, it isn't reset between the games,
+/// so the other players never take the new moves for the old ones
+pub struct MoveSequence {
+    pub last : u32,
+}
+
+
+/// Tell the other players where the player is now, every move gets the next number
+pub fn send_move(ecs : &World, game_client : &mut ClientHandler, name : &str, idx : i32, depth : i32) {
+    let mut sequence = ecs.fetch_mut::<MoveSequence>();
+    sequence.last += 1;
+    game_client.send_message(&ProtocolMessage::Move { name : name.to_string(), idx, depth, seq : sequence.last });
+}
 
 
-/// Move player if the new position is not blocked
-pub fn try_move_player(current_depth : i32, name : &String, game_client : &mut ClientHandler, delta_x : i32, delta_y : i32, ecs : &mut World) {
+/// Move player if the new position is not blocked.
+/// In the co-op game the player swaps places with the ally instead of attacking.
+/// Only the tile the player has ended up on is sent
+pub fn try_move_player(current_depth : i32, name : &str, game_client : &mut ClientHandler, delta_x : i32, delta_y : i32, ecs : &mut World) {
     let mut positions = ecs.write_storage::<Position>();
     let mut players = ecs.write_storage::<Player>();
     let mut viewsheds = ecs.write_storage::<Viewshed>();
     let combat_stats = ecs.read_storage::<CombatStats>();
+    let enemies = ecs.read_storage::<Enemy>();
+    let names = ecs.read_storage::<Name>();
     let map = ecs.fetch::<Map>();
+    let mode = *ecs.fetch::<GameMode>();
     let entities = ecs.entities();
     let mut wants_to_melee = ecs.write_storage::<WantsToMelee>();
+    let mut swap = None;
+    let mut moved_to = None;
     
     for (entity, _player, pos, viewshed) in (&entities, &mut players, &mut positions, &mut viewsheds).join() {
         if pos.x + delta_x < 1 || pos.x + delta_x > map.width-1 || pos.y + delta_y < 1 || pos.y + delta_y > map.height-1 { return; }
         let destination_idx = xy_idx(pos.x + delta_x, pos.y + delta_y);
 
-        let message = format!("{{\"__MESSAGE__\":\"{} {} {}\"}}", name, destination_idx, current_depth).as_bytes().to_vec();
-        game_client.send_message(message);
+        let ally = map.tile_content[destination_idx].iter()
+            .find(|target| mode == GameMode::Coop && enemies.contains(**target));
+        if let Some(ally) = ally {
+            swap = Some((*ally, Position { x : pos.x, y : pos.y }));
+            pos.x += delta_x;
+            pos.y += delta_y;
+
+            let mut ppos = ecs.write_resource::<Point>();
+            ppos.x = pos.x;
+            ppos.y = pos.y;
+            viewshed.dirty = true;
+
+            moved_to = Some(destination_idx as i32);
+            continue;
+        }
 
         for potential_target in map.tile_content[destination_idx].iter() {
             let target = combat_stats.get(*potential_target);
@@ -35,21 +69,31 @@ pub fn try_move_player(current_depth : i32, name : &String, game_client : &mut C
         }
 
         if !map.blocked[destination_idx] {
-            pos.x = min(79, max(0, pos.x + delta_x));
-            pos.y = min(49, max(0, pos.y + delta_y));
+            pos.x = (pos.x + delta_x).clamp(0, 79);
+            pos.y = (pos.y + delta_y).clamp(0, 49);
 
             let mut ppos = ecs.write_resource::<Point>();
             ppos.x = pos.x;
             ppos.y = pos.y;
 
-            let destination_idx = xy_idx(ppos.x, ppos.y);
-
-            let message = format!("{{\"__MESSAGE__\":\"{} {} {}\"}}", name, destination_idx, current_depth).as_bytes().to_vec();
-            game_client.send_message(message);
+            moved_to = Some(xy_idx(ppos.x, ppos.y) as i32);
 
             viewshed.dirty = true;
         }
     }
+
+    if let Some(idx) = moved_to {
+        send_move(ecs, game_client, name, idx, current_depth);
+    }
+
+    // the ally takes the old place of the player, its game is told to move there
+    if let Some((ally, old_pos)) = swap {
+        if let Some(ally_name) = names.get(ally) {
+            let idx = xy_idx(old_pos.x, old_pos.y) as i32;
+            game_client.send_message(&ProtocolMessage::Swap { depth : current_depth, name : ally_name.name.clone(), idx });
+        }
+        positions.insert(ally, old_pos).expect("Unable to move ally");
+    }
 }
 
 
@@ -95,6 +139,21 @@ pub fn try_next_level(ecs : &mut World) -> bool {
 }
 
 
+/// Go to previous level if the tile is upstairs
+pub fn try_previous_level(ecs : &mut World) -> bool {
+    let player_pos = ecs.fetch::<Point>();
+    let map = ecs.fetch::<Map>();
+    let player_idx = xy_idx(player_pos.x, player_pos.y);
+    if map.tiles[player_idx] == TileType::UpStairs {
+        true
+    } else {
+        let mut gamelog = ecs.fetch_mut::<GameLog>();
+        gamelog.entries.push("There is no way up from here".into());
+        false
+    }
+}
+
+
 /// Handles player input
 pub fn player_input(gs : &mut State, ctx : &mut Rltk) -> RunState {
     
@@ -137,6 +196,21 @@ pub fn player_input(gs : &mut State, ctx : &mut Rltk) -> RunState {
             // remove Item
             VirtualKeyCode::R => return RunState::ShowRemoveItem,
 
+            // talk to other players
+            VirtualKeyCode::T => {
+                if gs.play_mode != Some(super::PlayMode::Multiplayer) {
+                    let mut gamelog = gs.ecs.fetch_mut::<GameLog>();
+                    gamelog.entries.push("There is nobody to talk to".to_string());
+                    return RunState::AwaitingInput;
+                }
+                if !gs.game_client.has_capability(super::CHAT) {
+                    let mut gamelog = gs.ecs.fetch_mut::<GameLog>();
+                    gamelog.entries.push("The server doesn't support the chat".to_string());
+                    return RunState::AwaitingInput;
+                }
+                return RunState::Chatting;
+            }
+
             // save and quit
             VirtualKeyCode::Escape => return RunState::MainMenu { menu_selection: super::MainMenuSelection::Quit },
 
@@ -146,6 +220,11 @@ pub fn player_input(gs : &mut State, ctx : &mut Rltk) -> RunState {
                     return RunState::NextLevel
                 }
             }
+            VirtualKeyCode::Comma => {
+                if try_previous_level(&mut gs.ecs) {
+                    return RunState::PreviousLevel
+                }
+            }
 
             // Skip turn
             VirtualKeyCode::Numpad5 => return skip_turn(&mut gs.ecs),
@@ -0,0 +1,435 @@
+// This file describes all messages that the client and the server exchange
+
+use serde::{Serialize, Deserialize};
+
+use crate::leaderboard::{LeaderboardEntry, RatingColumn};
+use crate::map_codec::{MSGPACK_MAPS, COMPRESSED_MAPS};
+
+
+/// Version of the protocol, is increased on every change of the messages or the levels
+pub const PROTOCOL_VERSION : u32 = 24;
+
+/// The oldest version of the protocol the other side may speak. It is increased only when the older
+/// games can't play with this one anymore: version 15 has stamped the prefabs into the levels,
+/// so the older games build other levels from the same seed, version 21 has told the hits of the players
+/// from the hits of the monsters, the older games send every hit as the one of the monster,
+/// version 22 has the authority send its turns too, the server counts the turns of every game
+pub const MIN_PROTOCOL_VERSION : u32 = 22;
+
+/// Players may talk to each other with the chat messages
+pub const CHAT : &str = "chat";
+
+/// Optional features this build supports, they are used only if both sides have them.
+/// A new feature the older games can live without is added here instead of increasing the minimal version
+pub const CAPABILITIES : &[&str] = &[CHAT, MSGPACK_MAPS, COMPRESSED_MAPS];
+
+/// The biggest number of players in one room
+pub const MAX_ROOM_PLAYERS : u32 = 8;
+
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+/// Whether the players of the room are allies or rivals
+pub enum GameMode {
+    /// Players can't hurt each other and swap places when they bump
+    #[default]
+    Coop,
+    /// Players fight each other with melee and spells
+    Pvp,
+}
+
+
+impl GameMode {
+
+    /// Name of the mode for the menus
+    pub fn label(&self) -> &'static str {
+        match self {
+            GameMode::Coop => "co-op",
+            GameMode::Pvp => "PvP",
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+/// Who has dealt the hit
+pub enum AttackerKind {
+    #[default]
+    Monster,
+    Player,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+/// Open game room in the lobby
+pub struct RoomInfo {
+    pub name : String,
+    pub players : u32,
+    pub max_players : u32,
+    #[serde(default)]
+    pub mode : GameMode,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+/// Player of the room the spectators may watch
+pub struct PlayerInfo {
+    pub name : String,
+    pub depth : i32,
+    /// Tile of the last move, unknown until the player moves on the depth
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idx : Option<i32>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+/// State of one shared monster or item of the level
+pub struct SharedEntity {
+    pub id : u64,
+    pub name : String,
+    pub idx : i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hp : Option<i32>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+/// Stores all messages of the network protocol.
+/// Requests and replies share the same variant, the reply fields are left empty by the client
+pub enum ProtocolMessage {
+    /// The first message of every connection. The client sends its version and capabilities,
+    /// the server replies with the agreed version, the common capabilities or the rejection reason
+    #[serde(rename = "__HELLO__")]
+    Hello {
+        version : u32,
+        #[serde(default)]
+        capabilities : Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error : Option<String>,
+        /// Hash of the prefabs the game builds the levels with, the server refuses the game with other prefabs.
+        /// The older games don't send it
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        prefabs : Option<u64>,
+    },
+
+    /// Player moved to the tile with the given index. The number of the move grows with every move of the player,
+    /// so the late moves that come after the newer ones are dropped
+    #[serde(rename = "__MESSAGE__")]
+    Move {
+        name : String,
+        idx : i32,
+        depth : i32,
+        #[serde(default)]
+        seq : u32,
+    },
+
+    /// Player's hp has changed
+    #[serde(rename = "__DAMAGE__")]
+    Damage {
+        name : String,
+        hp : i32,
+    },
+
+    /// Player has left the given depth
+    #[serde(rename = "__CHANGE__")]
+    Change {
+        name : String,
+        depth : i32,
+    },
+
+    /// Player has died on the given depth, the server frees the name
+    /// and writes the game to the leaderboard with the deepest level of the game
+    #[serde(rename = "__DIED__")]
+    Died {
+        name : String,
+        depth : i32,
+        /// The older games don't send it, their deepest level is the one they die on
+        #[serde(default)]
+        max_depth : i32,
+        #[serde(default)]
+        kills : u32,
+        #[serde(default)]
+        turns : u32,
+        #[serde(default)]
+        cause : String,
+    },
+
+    /// Sent by the server: the message of the game is impossible, it isn't passed on to the other players
+    #[serde(rename = "__REJECTED__")]
+    Rejected {
+        message : Box<ProtocolMessage>,
+        reason : String,
+    },
+
+    /// Sent by the server: the player has disconnected or gone to another room
+    #[serde(rename = "__LEFT__")]
+    Left {
+        name : String,
+    },
+
+    /// Check if the name is free, the server sets `accepted` in the reply
+    #[serde(rename = "__IS_NAME__")]
+    IsName {
+        name : String,
+        #[serde(default)]
+        accepted : bool,
+    },
+
+    /// Log in to the account of the name with the password or the token of the earlier login,
+    /// `register` creates the account first. The server replies with the token or the error,
+    /// the name is taken for the client like the accepted `IsName`
+    #[serde(rename = "__LOGIN__")]
+    Login {
+        name : String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        password : Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        token : Option<String>,
+        #[serde(default)]
+        register : bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error : Option<String>,
+    },
+
+    /// Ask for the seed of the room, every level is built from it and its depth.
+    /// Outside of a room the server gives back the suggested seed
+    #[serde(rename = "__SEED__")]
+    Seed {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        seed : Option<u64>,
+    },
+
+    /// Tell the server the current depth of the player
+    #[serde(rename = "__TRACK_ME__")]
+    TrackMe {
+        name : String,
+        depth : i32,
+    },
+
+    /// Ask for the page of the leaderboard of the room sorted by the column,
+    /// the server fills in the `entries` of the page and the `total` number of the entries.
+    /// The player in the room gets the games of that room, the games of all rooms are shown only if they are asked for
+    #[serde(rename = "__RATING__")]
+    Rating {
+        #[serde(default)]
+        column : RatingColumn,
+        #[serde(default)]
+        descending : bool,
+        #[serde(default)]
+        page : u32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        room : Option<String>,
+        #[serde(default)]
+        all_rooms : bool,
+        #[serde(default)]
+        entries : Vec<LeaderboardEntry>,
+        #[serde(default)]
+        total : u32,
+    },
+
+    /// Chat line of the named player. It is shown only on the given depth, or to the whole room without it
+    #[serde(rename = "__CHAT__")]
+    Chat {
+        name : String,
+        text : String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        depth : Option<i32>,
+    },
+
+    /// Ask for the open rooms, the server fills in `rooms`
+    #[serde(rename = "__ROOMS__")]
+    Rooms {
+        #[serde(default)]
+        rooms : Vec<RoomInfo>,
+    },
+
+    /// Create the room and join it. The server picks the seed if it is not given,
+    /// the reply has the seed of the room or the reason of the failure
+    #[serde(rename = "__CREATE_ROOM__")]
+    CreateRoom {
+        room : String,
+        max_players : u32,
+        #[serde(default)]
+        mode : GameMode,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        seed : Option<u64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error : Option<String>,
+    },
+
+    /// Join the room, all game messages go only to the players of the same room, the server announces
+    /// the mode and the size of the room in the reply. A game that comes back after a reconnect sends its seed,
+    /// mode and size, so the room is restored with the same settings if the server has lost it.
+    /// A spectator only watches the game: it gets the messages of the room, but isn't counted as a player
+    #[serde(rename = "__JOIN_ROOM__")]
+    JoinRoom {
+        room : String,
+        #[serde(default)]
+        spectator : bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        seed : Option<u64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        mode : Option<GameMode>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_players : Option<u32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error : Option<String>,
+    },
+
+    /// Sent by the server: the named player simulates the monsters of the depth,
+    /// the games of other players on it only show the results
+    #[serde(rename = "__AUTHORITY__")]
+    Authority {
+        depth : i32,
+        name : String,
+    },
+
+    /// Shared monster has moved to the tile with the given index
+    #[serde(rename = "__ENTITY_MOVE__")]
+    EntityMove {
+        depth : i32,
+        id : u64,
+        idx : i32,
+    },
+
+    /// Hp of the shared monster has changed, it is dead if the hp is below 1
+    #[serde(rename = "__ENTITY_HP__")]
+    EntityHp {
+        depth : i32,
+        id : u64,
+        hp : i32,
+    },
+
+    /// Shared item has been picked up by the named player
+    #[serde(rename = "__ITEM_PICKUP__")]
+    ItemPickup {
+        depth : i32,
+        id : u64,
+        name : String,
+    },
+
+    /// The named player has dropped the item on the tile with the given index,
+    /// the games that don't know the item spawn it by the name
+    #[serde(rename = "__ITEM_DROP__")]
+    ItemDrop {
+        depth : i32,
+        id : u64,
+        item : String,
+        idx : i32,
+        name : String,
+    },
+
+    /// Player has confused the shared monster with a scroll, the authority of the depth applies it
+    #[serde(rename = "__ENTITY_CONFUSION__")]
+    EntityConfusion {
+        depth : i32,
+        id : u64,
+        turns : i32,
+    },
+
+    /// Player has hit the shared monster, the authority of the depth applies the damage
+    #[serde(rename = "__HIT_ENTITY__")]
+    HitEntity {
+        depth : i32,
+        id : u64,
+        amount : i32,
+    },
+
+    /// The named player has been hit by the attacker, the game of that player applies the damage and reports the new hp.
+    /// The attacker is the name of the monster or of the player that has sent the hit
+    #[serde(rename = "__HIT_PLAYER__")]
+    HitPlayer {
+        depth : i32,
+        name : String,
+        amount : i32,
+        #[serde(default)]
+        attacker : String,
+        #[serde(default)]
+        attacker_kind : AttackerKind,
+    },
+
+    /// The named player is moved to the tile, another player of the co-op room has swapped places with them
+    #[serde(rename = "__SWAP__")]
+    Swap {
+        depth : i32,
+        name : String,
+        idx : i32,
+    },
+
+    /// Player has made a turn, the authority of the depth lets the monsters make theirs.
+    /// The authority sends its turns too, the server counts them and doesn't pass them on
+    #[serde(rename = "__TURN__")]
+    Turn {
+        depth : i32,
+    },
+
+    /// Ask for the players of the room and their depths, the server fills in `players`
+    #[serde(rename = "__PLAYERS__")]
+    Players {
+        #[serde(default)]
+        players : Vec<PlayerInfo>,
+    },
+
+    /// Sent by the server to the authority when another player comes to the depth.
+    /// A spectator sends it for the depth it starts to watch, the server passes it on to the authority
+    #[serde(rename = "__WORLD_REQUEST__")]
+    WorldRequest {
+        depth : i32,
+    },
+
+    /// All shared entities of the depth, the authority sends them for the players that come later
+    /// and when it leaves the depth. The server keeps the last ones and sends them `stored`
+    /// to the player that comes back to the depth nobody is on, even that player's game applies them
+    #[serde(rename = "__WORLD__")]
+    World {
+        depth : i32,
+        entities : Vec<SharedEntity>,
+        #[serde(default)]
+        stored : bool,
+        /// The entities in the encoding of `map_codec` both sides have agreed on, `entities` are empty then
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        packed : Option<String>,
+    },
+}
+
+
+impl ProtocolMessage {
+
+    /// Greeting with the version and the capabilities of this build and the hash of its prefabs
+    pub fn hello(prefabs : u64) -> Self {
+        ProtocolMessage::Hello {
+            version : PROTOCOL_VERSION,
+            capabilities : CAPABILITIES.iter().map(|c| c.to_string()).collect(),
+            error : None,
+            prefabs : Some(prefabs),
+        }
+    }
+
+    /// Serialize message to json
+    pub fn encode(&self) -> String {
+        serde_json::to_string(self).expect("Can't serialize message")
+    }
+
+    /// Deserialize message from json
+    pub fn decode(data : &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(data)
+    }
+
+    /// Check if this message is the server's answer to the given request
+    pub fn is_reply_to(&self, request : &ProtocolMessage) -> bool {
+        match (self, request) {
+            (ProtocolMessage::Hello { .. }, ProtocolMessage::Hello { .. }) => true,
+            (ProtocolMessage::IsName { name, .. }, ProtocolMessage::IsName { name : asked, .. }) => name == asked,
+            (ProtocolMessage::Login { name, .. }, ProtocolMessage::Login { name : asked, .. }) => name == asked,
+            (ProtocolMessage::Seed { .. }, ProtocolMessage::Seed { .. }) => true,
+            (ProtocolMessage::Rating { column, descending, page, .. },
+                ProtocolMessage::Rating { column : asked_column, descending : asked_descending, page : asked_page, .. }) => {
+                column == asked_column && descending == asked_descending && page == asked_page
+            }
+            (ProtocolMessage::Rooms { .. }, ProtocolMessage::Rooms { .. }) => true,
+            (ProtocolMessage::Players { .. }, ProtocolMessage::Players { .. }) => true,
+            (ProtocolMessage::CreateRoom { room, .. }, ProtocolMessage::CreateRoom { room : asked, .. }) => room == asked,
+            (ProtocolMessage::JoinRoom { room, .. }, ProtocolMessage::JoinRoom { room : asked, .. }) => room == asked,
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+// This file stores the stats of the current game, they go to the leaderboard when the player dies
+
+
+#[derive(Default, Clone, Debug)]
+/// What the player has done in this game
+pub struct RunStats {
+    pub kills : u32,
+    pub turns : u32,
+    /// The deepest level the player has come to, the player may go up and die above it
+    pub max_depth : i32,
+    /// Who has dealt the killing blow, empty while the player is alive
+    pub cause : String,
+}
@@ -1,49 +0,0 @@
-#include "WebsocketServer.h"
-
-#include <iostream>
-#include <thread>
-#include <asio/io_service.hpp>
-
-//The port number the WebSocket server listens on
-#define PORT_NUMBER 6881
-
-int main(int argc, char* argv[])
-{
-	//Create the event loop for the main thread, and the WebSocket server
-	asio::io_service mainEventLoop;
-	WebsocketServer server;
-	
-	//Register our network callbacks, ensuring the logic is run on the main thread's event loop
-	server.connect([&mainEventLoop, &server](ClientConnection conn)
-	{
-		mainEventLoop.post([conn, &server]()
-		{
-			std::clog << "Connection opened." << std::endl;
-			std::clog << "There are now " << server.numConnections() << " open connections." << std::endl;
-			
-			//Send a hello message to the client
-			// server.sendMessage(conn, "hello client", Json::Value());
-		});
-	});
-
-	//Register if client want to disconnect
-	server.disconnect([&mainEventLoop, &server](ClientConnection conn)
-	{
-		mainEventLoop.post([conn, &server]()
-		{
-			std::clog << "Connection closed." << std::endl;
-			std::clog << "There are now " << server.numConnections() << " open connections." << std::endl;
-		});
-	});
-
-	//Start the networking thread
-	std::thread serverThread([&server]() {
-		server.run(PORT_NUMBER);
-	});
-	
-	//Start the event loop for the main thread
-	asio::io_service::work work(mainEventLoop);
-	mainEventLoop.run();
-	
-	return 0;
-}
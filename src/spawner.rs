@@ -1,10 +1,10 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use rltk::{RGB, RandomNumberGenerator};
 use specs::prelude::*;
 use specs::saveload::{MarkedBuilder, SimpleMarker};
 
-use super::{CombatStats, Player, Renderable, Name, Position, Viewshed, Monster, BlocksTile, Rect, Item, ProvidesHealing, Consumable, Ranged, InflictDamage, Confusion, SerializeMe, Enemy};
+use super::{CombatStats, Player, Renderable, Name, Position, Viewshed, Monster, BlocksTile, Item, ProvidesHealing, Consumable, Ranged, InflictDamage, Confusion, SerializeMe, Enemy, RemoteMotion, NetworkId};
 use super::constants::*;
 use super::{AreaOfEffect, EquipmentSlot, Equippable, MeleePowerBonus, DefenseBonus};
 use super::random_table::*;
@@ -23,7 +23,7 @@ pub fn player(ecs: &mut World, player_x: i32, player_y: i32) -> Entity {
         .with(Player {})
         .with(Viewshed{visible_tiles: Vec::new(), range: 8, dirty: true})
         .with(Name {name: "Player".to_string()})
-        .with(CombatStats{max_hp: 30, hp: 30, defense: 2, power: 5})
+        .with(CombatStats{max_hp: PLAYER_MAX_HP, hp: PLAYER_MAX_HP, defense: 2, power: 5})
         .marked::<SimpleMarker<SerializeMe>>()
         .build()
 }
@@ -41,13 +41,33 @@ pub fn enemy(ecs: &mut World, enemy_x: i32, enemy_y: i32, enemy_name: String) ->
         })
         .with(BlocksTile{})
         .with(Enemy {})
+        .with(RemoteMotion::default())
         .with(Name {name: enemy_name})
-        .with(CombatStats{max_hp: 30, hp: 30, defense: 2, power: 5})
+        .with(CombatStats{max_hp: PLAYER_MAX_HP, hp: PLAYER_MAX_HP, defense: 2, power: 5})
         .build()
 }
 
+/// Turns the dead enemy into a corpse that doesn't block the way
+pub fn corpse(ecs : &mut World, enemy : Entity, enemy_name : &str) {
+    ecs.write_storage::<Enemy>().remove(enemy);
+    ecs.write_storage::<RemoteMotion>().remove(enemy);
+    ecs.write_storage::<BlocksTile>().remove(enemy);
+    ecs.write_storage::<CombatStats>().remove(enemy);
+    ecs.write_storage::<Renderable>()
+        .insert(enemy, Renderable {
+            glyph: rltk::to_cp437('%'),
+            fg: RGB::named(rltk::GREY),
+            bg: RGB::named(rltk::BLACK),
+            render_order: 3,
+        })
+        .expect("Unable to insert corpse");
+    ecs.write_storage::<Name>()
+        .insert(enemy, Name {name: format!("Corpse of {}", enemy_name)})
+        .expect("Unable to insert corpse");
+}
+
 /// Spawns a random monster at a given location
-pub fn random_monster(ecs: &mut World, x: i32, y: i32) {
+pub fn random_monster(ecs: &mut World, x: i32, y: i32) -> Entity {
     let roll: i32;
     {
         let mut rng =  ecs.write_resource::<RandomNumberGenerator>();
@@ -61,19 +81,19 @@ pub fn random_monster(ecs: &mut World, x: i32, y: i32) {
 
 
 /// Return orc monster
-fn orc(ecs: &mut World, x: i32, y: i32) {
-    monster(ecs, x, y, rltk::to_cp437('o'), "Orc");
+fn orc(ecs: &mut World, x: i32, y: i32) -> Entity {
+    monster(ecs, x, y, rltk::to_cp437('o'), "Orc")
 }
 
 
 /// Return goblin monster
-fn goblin(ecs: &mut World, x: i32, y: i32) {
-    monster(ecs, x, y, rltk::to_cp437('g'), "Goblin");
+fn goblin(ecs: &mut World, x: i32, y: i32) -> Entity {
+    monster(ecs, x, y, rltk::to_cp437('g'), "Goblin")
 }
 
 
 /// Return a monster with given position and glyph
-fn monster<S : ToString>(ecs: &mut World, x: i32, y: i32, glyph: rltk::FontCharType, name: S) {
+fn monster<S : ToString>(ecs: &mut World, x: i32, y: i32, glyph: rltk::FontCharType, name: S) -> Entity {
     ecs.create_entity()
         .with(Position {x, y})
         .with(Renderable {
@@ -86,64 +106,95 @@ fn monster<S : ToString>(ecs: &mut World, x: i32, y: i32, glyph: rltk::FontCharT
         .with(Monster{})
         .with(Name{name: name.to_string()})
         .with(BlocksTile{})
-        .with(CombatStats{max_hp: 16, hp: 16, defense: 1, power: 4})
+        .with(CombatStats{max_hp: MONSTER_MAX_HP, hp: MONSTER_MAX_HP, defense: 1, power: 4})
         .marked::<SimpleMarker<SerializeMe>>()
-        .build();
+        .build()
 }
 
 
 #[allow(clippy::map_entry)]
-/// Spawn rooms with monsters and items from random table
-pub fn spawn_room(ecs: &mut World, room: &Rect, map_depth : i32) {
+/// Spawn monsters and items from random table in the region of the level.
+/// The spawns depend only on the given generator, so the level can be rebuilt by every client
+pub fn spawn_region(ecs: &mut World, region: &[usize], map_depth : i32, rng : &mut RandomNumberGenerator) {
+    if region.is_empty() {
+        return;
+    }
     let spawn_table = room_table(map_depth);
-    let mut spawn_points : HashMap<usize, String> = HashMap::new();
-
-    // scope to keep the borrow happy
-    {
-        let mut rng = ecs.write_resource::<RandomNumberGenerator>();
-        let num_spawns = rng.roll_dice(1, MAX_MONSTERS + 3) + (map_depth - 1) - 3;
-
-        for _i in 0..num_spawns {
-            let mut added = false;
-            let mut tries = 0;
-            while !added && tries < 20 {
-                let x = (room.x1 + rng.roll_dice(1, i32::abs(room.x2 - room.x1))) as usize;
-                let y = (room.y1 + rng.roll_dice(1, i32::abs(room.y2 - room.y1))) as usize;
-                let idx = (y * MAPWIDTH) + x;
-                if !spawn_points.contains_key(&idx) {
-                    spawn_points.insert(idx, spawn_table.roll(&mut rng));
-                    added = true;
-                } else {
-                    tries += 1;
-                }
+    let mut spawn_points : BTreeMap<usize, String> = BTreeMap::new();
+
+    let num_spawns = i32::min(region.len() as i32, rng.roll_dice(1, MAX_MONSTERS + 3) + (map_depth - 1) - 3);
+
+    for _i in 0..num_spawns {
+        let mut added = false;
+        let mut tries = 0;
+        while !added && tries < 20 {
+            let idx = region[rng.roll_dice(1, region.len() as i32) as usize - 1];
+            if !spawn_points.contains_key(&idx) {
+                spawn_points.insert(idx, spawn_table.roll(rng));
+                added = true;
+            } else {
+                tries += 1;
             }
         }
     }
 
     // actually spawn the monsters and items
-    for spawn in spawn_points.iter() {
-        let x = (*spawn.0 % MAPWIDTH) as i32;
-        let y = (*spawn.0 / MAPWIDTH) as i32;
-
-        match spawn.1.as_ref() {
-            "Goblin" => goblin(ecs, x, y),
-            "Orc" => orc(ecs, x, y),
-            "Health Potion" => health_potion(ecs, x, y),
-            "Fireball Scroll" => fireball_scroll(ecs, x, y),
-            "Confusion Scroll" => confusion_scroll(ecs, x, y),
-            "Magic Missible Scroll" => magic_missible_scroll(ecs, x, y),
-            "Dagger" => dagger(ecs, x, y),
-            "Shield" => shield(ecs, x, y),
-            "Longsword" => longsword(ecs, x, y),
-            "Tower Shield" => tower_shield(ecs, x, y),
-            _ => {}
-        }   
+    for (idx, name) in spawn_points.iter() {
+        spawn_on_tile(ecs, name, *idx, map_depth);
+    }
+}
+
+
+/// Spawn the monster or the item of the level on the tile, the tile is the network id of the entity
+pub fn spawn_on_tile(ecs: &mut World, name: &str, idx: usize, map_depth : i32) {
+    let x = (idx % MAPWIDTH) as i32;
+    let y = (idx / MAPWIDTH) as i32;
+
+    match spawn_named(ecs, name, x, y) {
+        Some(entity) => {
+            ecs.write_storage::<NetworkId>()
+                .insert(entity, NetworkId::level(map_depth, idx))
+                .expect("Unable to insert network id");
+        }
+        None => println!("Unknown monster or item {}", name),
     }
 }
 
 
+/// Builds the monster or the item on the tile
+type SpawnFn = fn(&mut World, i32, i32) -> Entity;
+
+/// Monsters and items that are spawned by their names
+const NAMED_SPAWNS : &[(&str, SpawnFn)] = &[
+    ("Goblin", goblin),
+    ("Orc", orc),
+    ("Health Potion", health_potion),
+    ("Fireball Scroll", fireball_scroll),
+    ("Confusion Scroll", confusion_scroll),
+    ("Magic Missible Scroll", magic_missible_scroll),
+    ("Dagger", dagger),
+    ("Shield", shield),
+    ("Longsword", longsword),
+    ("Tower Shield", tower_shield),
+];
+
+
+/// Spawn the monster or the item by its name
+pub fn spawn_named(ecs: &mut World, name: &str, x: i32, y: i32) -> Option<Entity> {
+    NAMED_SPAWNS.iter()
+        .find(|(known, _)| *known == name)
+        .map(|(_, spawn)| spawn(ecs, x, y))
+}
+
+
+/// Check if the monster or the item of the name can be spawned
+pub fn is_spawnable(name : &str) -> bool {
+    NAMED_SPAWNS.iter().any(|(known, _)| *known == name)
+}
+
+
 /// Return a health potion entity with given position
-fn health_potion(ecs: &mut World, x : i32, y: i32) {
+fn health_potion(ecs: &mut World, x : i32, y: i32) -> Entity {
     ecs.create_entity()
         .with(Position{x, y})
         .with(Renderable {
@@ -157,12 +208,12 @@ fn health_potion(ecs: &mut World, x : i32, y: i32) {
         .with(ProvidesHealing {heal_amount: 8})
         .with(Consumable {})
         .marked::<SimpleMarker<SerializeMe>>()
-        .build();
+        .build()
 }
 
 
 /// Return a magic missible scroll with given position
-fn magic_missible_scroll(ecs : &mut World, x : i32, y : i32) {
+fn magic_missible_scroll(ecs : &mut World, x : i32, y : i32) -> Entity {
     ecs.create_entity()
         .with(Position {x, y})
         .with(Renderable {
@@ -177,7 +228,7 @@ fn magic_missible_scroll(ecs : &mut World, x : i32, y : i32) {
         .with(Ranged {range : 6})
         .with(InflictDamage {damage : 8})
         .marked::<SimpleMarker<SerializeMe>>()
-        .build();
+        .build()
 }
 
 // fn random_item(ecs : &mut World, x : i32, y : i32) {
@@ -195,7 +246,7 @@ fn magic_missible_scroll(ecs : &mut World, x : i32, y : i32) {
 // }
 
 /// Return a fireball scroll with given position
-fn fireball_scroll(ecs : &mut World, x : i32, y : i32) {
+fn fireball_scroll(ecs : &mut World, x : i32, y : i32) -> Entity {
     ecs.create_entity()
         .with(Position {x, y})
         .with(Renderable {
@@ -211,12 +262,12 @@ fn fireball_scroll(ecs : &mut World, x : i32, y : i32) {
         .with(InflictDamage {damage : 20})
         .with(AreaOfEffect {radius : 3})
         .marked::<SimpleMarker<SerializeMe>>()
-        .build();
+        .build()
 }
 
 
 /// Return a confusion scroll with given position
-fn confusion_scroll(ecs : &mut World, x : i32, y : i32) {
+fn confusion_scroll(ecs : &mut World, x : i32, y : i32) -> Entity {
     ecs.create_entity()
         .with(Position {x, y})
         .with(Renderable {
@@ -231,12 +282,12 @@ fn confusion_scroll(ecs : &mut World, x : i32, y : i32) {
         .with(Ranged {range : 6})
         .with(Confusion {turns : 4})
         .marked::<SimpleMarker<SerializeMe>>()
-        .build();
+        .build()
 }
 
 
 /// Return a dagger with given position
-fn dagger(ecs : &mut World, x : i32, y : i32) {
+fn dagger(ecs : &mut World, x : i32, y : i32) -> Entity {
     ecs.create_entity()
         .with(Position {x, y})
         .with(Renderable {
@@ -250,12 +301,12 @@ fn dagger(ecs : &mut World, x : i32, y : i32) {
         .with(Equippable {slot : EquipmentSlot::Melee})
         .with(MeleePowerBonus {power : 2})
         .marked::<SimpleMarker<SerializeMe>>()
-        .build();
+        .build()
 }
 
 
 /// Return a shield with given position
-fn shield(ecs : &mut World, x : i32, y : i32) {
+fn shield(ecs : &mut World, x : i32, y : i32) -> Entity {
     ecs.create_entity()
         .with(Position {x, y})
         .with(Renderable {
@@ -269,12 +320,12 @@ fn shield(ecs : &mut World, x : i32, y : i32) {
         .with(Equippable {slot : EquipmentSlot::Melee})
         .with(DefenseBonus {defense : 1})
         .marked::<SimpleMarker<SerializeMe>>()
-        .build();
+        .build()
 }
 
 
 /// Return a longsword with given position
-fn longsword(ecs : &mut World, x : i32, y : i32) {
+fn longsword(ecs : &mut World, x : i32, y : i32) -> Entity {
     ecs.create_entity()
         .with(Position {x, y})
         .with(Renderable {
@@ -288,12 +339,12 @@ fn longsword(ecs : &mut World, x : i32, y : i32) {
         .with(Equippable {slot : EquipmentSlot::Melee})
         .with(MeleePowerBonus { power : 4})
         .marked::<SimpleMarker<SerializeMe>>()
-        .build();
+        .build()
 }
 
 
 /// Return a tower shield with given position
-fn tower_shield(ecs : &mut World, x : i32, y : i32) {
+fn tower_shield(ecs : &mut World, x : i32, y : i32) -> Entity {
     ecs.create_entity()
         .with(Position {x, y})
         .with(Renderable {
@@ -307,5 +358,5 @@ fn tower_shield(ecs : &mut World, x : i32, y : i32) {
         .with(Equippable { slot : EquipmentSlot::Shield})
         .with(DefenseBonus {defense : 3})
         .marked::<SimpleMarker<SerializeMe>>()
-        .build();
+        .build()
 }
@@ -0,0 +1,1755 @@
+// This file handles the state machine of the game: the menus, the turns, the levels and the messages of the server
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use rltk::{Rltk, GameState, Point, RGB, RandomNumberGenerator};
+use specs::prelude::*;
+use specs::saveload::{SimpleMarker, SimpleMarkerAllocator};
+
+use super::*;
+use super::level_store::{self, LevelStore};
+use super::map_builders::prefabs::Prefab;
+
+
+#[derive(PartialEq, Copy, Clone)]
+/// The states of a finite automaton in which the player can be
+pub enum RunState {
+    AwaitingHandshake,
+    EnteringName,
+    AwaitingNameCheck,
+    ChoosingAccount {
+        selection : gui::AccountSelection,
+    },
+    EnteringPassword {
+        register : bool,
+    },
+    AwaitingLogin,
+    Lobby {
+        selection : usize,
+    },
+    CreatingRoom,
+    AwaitingRoom,
+    AwaitingSeed,
+    AwaitingInput,
+    ShowRating, 
+    PreRun, 
+    PlayerTurn,
+    MonsterTurn,
+    ShowInventory,
+    ShowDropItem,
+    ShowTargeting {
+        range : i32,
+        item : Entity,
+    },
+    MainMenu {
+        menu_selection : gui::MainMenuSelection,
+    },
+    NextLevel,
+    PreviousLevel,
+    ShowRemoveItem,
+    Chatting,
+    Spectating,
+    GameOver,
+}
+
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+/// Last known place of the remote player on any depth and the number of its last move
+pub struct KnownPlayer {
+    /// Unknown after the player has left the depth until its first move on the next one
+    pub depth : Option<i32>,
+    pub idx : i32,
+    pub seq : u32,
+}
+
+
+#[derive(PartialEq, Copy, Clone)]
+/// Whether the game is played alone or with other players through the server,
+/// the spectator only watches the players of a room
+pub enum PlayMode {
+    SinglePlayer,
+    Multiplayer,
+    Spectator,
+}
+
+
+/// Handler for ecs and clients
+pub struct State {
+    pub ecs : World,
+    pub game_client : ClientHandler,
+    pub config : Config,
+    pub play_mode : Option<PlayMode>,
+    pub network_error : Option<String>,
+    pub game_seed : u64,
+    pub player_name : String,
+    pub enemies : Vec<String>,
+    pub pending : Option<PendingRequest>,
+    pub resync : Option<PendingRequest>,
+    /// Room of the multiplayer game
+    pub room : Option<String>,
+    /// The biggest number of players in the room, the server restores the lost room with it
+    pub room_max_players : Option<u32>,
+    /// Open rooms shown in the lobby and the time they were asked for
+    pub rooms : Vec<RoomInfo>,
+    pub rooms_requested : Option<Instant>,
+    pub room_form : RoomForm,
+    /// Chat line the player is typing and who gets it
+    pub chat_text : String,
+    pub chat_scope : ChatScope,
+    /// Names of the players that simulate the monsters of every depth
+    pub authorities : HashMap<i32, String>,
+    /// Another player on this depth has made a turn, the monsters make theirs
+    pub remote_turn : bool,
+    /// Network ids of the items picked up by other players
+    pub remote_pickups : Vec<u64>,
+    /// Items dropped by other players: network id, tile index and the name of the player
+    pub remote_drops : Vec<(u64, i32, String)>,
+    /// Player the spectator follows, the players of the room and the time they were asked for
+    pub watched : Option<String>,
+    pub players : Vec<PlayerInfo>,
+    pub players_requested : Option<Instant>,
+    /// Sorting and the page of the leaderboard
+    pub rating : RatingView,
+    /// Where the other players of the room have been seen last
+    pub known_players : HashMap<String, KnownPlayer>,
+    /// Password of the account being entered and the tokens of the earlier logins
+    pub password : String,
+    pub tokens : accounts::TokenStore,
+    /// Hand-made rooms and vaults the levels are built with
+    pub prefabs : Vec<Prefab>,
+}
+
+
+impl GameState for State {
+
+    /// Рandling the states of a end state machine every tick
+    fn tick(&mut self, ctx : &mut Rltk) {
+        ctx.cls();
+
+        self.receive_messages();
+
+        let mut newrunstate;
+        {
+            let runstate = self.ecs.fetch::<RunState>();
+            newrunstate = *runstate;
+        }
+
+        // draw map if current state is not in main menu
+        match newrunstate {
+            RunState::MainMenu {..} | RunState::Lobby {..} | RunState::CreatingRoom => {}
+            _ => {
+                draw_map(&self.ecs, ctx);
+
+                let positions = self.ecs.read_storage::<Position>();
+                let renderables = self.ecs.read_storage::<Renderable>();
+                let map = self.ecs.fetch::<Map>();
+
+                let mut data = (&positions, &renderables).join().collect::<Vec<_>>();
+                data.sort_by_key(|&a| std::cmp::Reverse(a.1.render_order));
+                
+                // render player and monsters
+                for (pos, render) in data.iter() {
+                    let idx = xy_idx(pos.x, pos.y);
+                    if map.visible_tiles[idx] {
+                        ctx.set(pos.x, pos.y, render.fg, render.bg, render.glyph);
+                    }
+                }
+
+                // the other players out of view are shown grey where they were seen last, until that tile is in view
+                let motions = self.ecs.read_storage::<RemoteMotion>();
+                for (pos, render, motion) in (&positions, &renderables, &motions).join() {
+                    if map.visible_tiles[xy_idx(pos.x, pos.y)] {
+                        continue;
+                    }
+                    if let Some(seen) = motion.last_seen.filter(|seen| !map.visible_tiles[*seen as usize]) {
+                        let (x, y) = idx_xy(seen);
+                        ctx.set(x, y, RGB::named(rltk::GREY), render.bg, render.glyph);
+                    }
+                }
+                gui::draw_ui(&self.ecs, ctx);
+            }
+        }
+
+        // handling end state machine
+        match newrunstate {
+            RunState::AwaitingHandshake => {
+                match self.wait_for_reply(ctx, "Connecting to the server...") {
+                    None => {
+                        self.game_client = ClientHandler::local();
+                        self.play_mode = None;
+                        newrunstate = RunState::MainMenu { menu_selection : MainMenuSelection::Multiplayer };
+                    }
+                    // the spectator has no name, it goes straight to the rooms
+                    Some(Reply::Ready(_)) if self.play_mode == Some(PlayMode::Spectator) => {
+                        self.rooms_requested = None;
+                        newrunstate = RunState::Lobby { selection : 0 };
+                    }
+                    Some(Reply::Ready(_)) => newrunstate = self.enter_name(),
+                    Some(_) => {}
+                }
+            }
+            RunState::EnteringName => {
+                self.run_systems();
+                self.ecs.maintain();
+                if gui::entering_name(ctx, &mut self.player_name).is_some() {
+                    newrunstate = self.check_name();
+                }
+            }
+            RunState::AwaitingNameCheck => {
+                match self.wait_for_reply(ctx, "Checking your name...") {
+                    None => {
+                        self.player_name.clear();
+                        newrunstate = RunState::EnteringName;
+                    }
+                    Some(Reply::Ready(reply)) => newrunstate = self.name_checked(reply),
+                    Some(_) => {}
+                }
+            }
+            RunState::ChoosingAccount { selection } => {
+                self.run_systems();
+                self.ecs.maintain();
+                match gui::account_menu(ctx, &self.player_name, selection) {
+                    gui::AccountMenuResult::NoSelection { selected } => newrunstate = RunState::ChoosingAccount { selection : selected },
+                    gui::AccountMenuResult::Selected { selected : gui::AccountSelection::Guest } => {
+                        let request = ProtocolMessage::IsName { name : self.player_name.clone(), accepted : false };
+                        self.pending = Some(self.game_client.request(request));
+                        newrunstate = RunState::AwaitingNameCheck;
+                    }
+                    gui::AccountMenuResult::Selected { selected } => {
+                        self.password.clear();
+                        newrunstate = RunState::EnteringPassword { register : selected == gui::AccountSelection::Register };
+                    }
+                    gui::AccountMenuResult::Back => {
+                        self.player_name.clear();
+                        newrunstate = RunState::EnteringName;
+                    }
+                }
+            }
+            RunState::EnteringPassword { register } => {
+                self.run_systems();
+                self.ecs.maintain();
+                match gui::entering_password(ctx, &mut self.password, register) {
+                    gui::PasswordResult::NoResponse => {}
+                    gui::PasswordResult::Entered => {
+                        let password = Some(std::mem::take(&mut self.password));
+                        let request = ProtocolMessage::Login { name : self.player_name.clone(), password, token : None, register, error : None };
+                        self.pending = Some(self.game_client.request(request));
+                        newrunstate = RunState::AwaitingLogin;
+                    }
+                    gui::PasswordResult::Back => {
+                        let selection = if register { gui::AccountSelection::Register } else { gui::AccountSelection::Login };
+                        newrunstate = RunState::ChoosingAccount { selection };
+                    }
+                }
+            }
+            RunState::AwaitingLogin => {
+                match self.wait_for_reply(ctx, "Logging in...") {
+                    None => newrunstate = RunState::ChoosingAccount { selection : gui::AccountSelection::Login },
+                    Some(Reply::Ready(ProtocolMessage::Login { token : Some(token), .. })) => {
+                        self.tokens.set(&self.config.server, &self.player_name, &token);
+                        if let Err(e) = self.tokens.save(Path::new(accounts::TOKEN_FILE)) {
+                            println!("{}", e);
+                        }
+                        newrunstate = self.name_accepted();
+                    }
+                    Some(Reply::Ready(ProtocolMessage::Login { error, .. })) => {
+                        // the saved token is useless after the failed login
+                        self.tokens.remove(&self.config.server, &self.player_name);
+                        let mut gamelog = self.ecs.fetch_mut::<GameLog>();
+                        gamelog.entries.push(error.unwrap_or_else(|| "The server has refused the login".to_string()));
+                        newrunstate = RunState::ChoosingAccount { selection : gui::AccountSelection::Login };
+                    }
+                    Some(_) => {}
+                }
+            }
+            RunState::Lobby { selection } => {
+                self.refresh_rooms();
+                match gui::lobby(self, ctx, selection) {
+                    gui::LobbyResult::NoResponse { selection } => newrunstate = RunState::Lobby { selection },
+                    gui::LobbyResult::Join { room } => {
+                        let spectator = self.play_mode == Some(PlayMode::Spectator);
+                        let request = ProtocolMessage::JoinRoom { room, spectator, seed : None, mode : None, max_players : None, error : None };
+                        self.pending = Some(self.game_client.request(request));
+                        newrunstate = RunState::AwaitingRoom;
+                    }
+                    gui::LobbyResult::Create => {
+                        self.room_form = RoomForm::new(self.config.seed);
+                        newrunstate = RunState::CreatingRoom;
+                    }
+                    gui::LobbyResult::Cancel => {
+                        self.game_client = ClientHandler::local();
+                        self.play_mode = None;
+                        self.network_error = None;
+                        newrunstate = RunState::MainMenu { menu_selection : MainMenuSelection::Multiplayer };
+                    }
+                }
+            }
+            RunState::CreatingRoom => {
+                match gui::create_room(ctx, &mut self.room_form) {
+                    gui::RoomFormResult::NoResponse => {}
+                    gui::RoomFormResult::Create => {
+                        self.pending = Some(self.game_client.request(self.room_form.request()));
+                        newrunstate = RunState::AwaitingRoom;
+                    }
+                    gui::RoomFormResult::Cancel => newrunstate = RunState::Lobby { selection : 0 },
+                }
+            }
+            RunState::AwaitingRoom => {
+                match self.wait_for_reply(ctx, "Joining the room...") {
+                    None => newrunstate = RunState::Lobby { selection : 0 },
+                    Some(Reply::Ready(ProtocolMessage::CreateRoom { error : Some(error), .. }))
+                    | Some(Reply::Ready(ProtocolMessage::JoinRoom { error : Some(error), .. })) => {
+                        self.network_error = Some(error);
+                        self.rooms_requested = None;
+                        newrunstate = RunState::Lobby { selection : 0 };
+                    }
+                    Some(Reply::Ready(ProtocolMessage::CreateRoom { room, mode, max_players, .. })) => {
+                        newrunstate = self.enter_room(room, mode, Some(max_players));
+                    }
+                    Some(Reply::Ready(ProtocolMessage::JoinRoom { room, mode : Some(mode), max_players, .. })) => {
+                        newrunstate = self.enter_room(room, mode, max_players);
+                    }
+                    Some(_) => {}
+                }
+            }
+            RunState::AwaitingSeed => {
+                match self.wait_for_reply(ctx, "Loading the level...") {
+                    None if self.play_mode == Some(PlayMode::Spectator) => newrunstate = self.stop_spectating(),
+                    // the game can go on with its own seed, but other players will have other levels
+                    None => {
+                        self.goto_level(1);
+                        newrunstate = RunState::PreRun;
+                    }
+                    Some(Reply::Ready(ProtocolMessage::Seed { seed })) => {
+                        if let Some(seed) = seed {
+                            self.game_seed = seed;
+                        }
+                        if self.play_mode == Some(PlayMode::Spectator) {
+                            newrunstate = self.start_spectating();
+                        } else {
+                            self.goto_level(1);
+                            newrunstate = RunState::PreRun;
+                        }
+                    }
+                    Some(_) => {}
+                }
+            }
+            RunState::PreRun => {
+                self.run_systems();
+                self.ecs.maintain();
+                newrunstate = RunState::AwaitingInput;
+            }
+            RunState::AwaitingInput => {
+                self.run_systems();
+                self.ecs.maintain();
+                newrunstate = player_input(self, ctx);
+                if newrunstate == RunState::AwaitingInput && std::mem::take(&mut self.remote_turn) {
+                    newrunstate = RunState::MonsterTurn;
+                }
+            }
+            RunState::ShowRating => {
+                let result = gui::show_rating(self, ctx);
+                match result {
+                    ItemMenuResult::Cancel => {
+                        // the connection was opened only to look at the leaderboard
+                        if self.play_mode.is_none() {
+                            self.game_client = ClientHandler::local();
+                        }
+                        newrunstate = RunState::MainMenu { menu_selection: MainMenuSelection::Rating };
+                    }
+                    _ => newrunstate = RunState::ShowRating,
+                }
+            }
+            RunState::PlayerTurn => {
+                self.ecs.write_resource::<RunStats>().turns += 1;
+                self.run_systems();
+                self.ecs.maintain();
+                newrunstate = RunState::MonsterTurn;
+            }
+            RunState::MonsterTurn => {
+                // the monsters are moved by the authority of the depth, the server counts the turns of every game
+                self.game_client.send_message(&ProtocolMessage::Turn { depth : self.current_depth() });
+                self.run_systems();
+                self.ecs.maintain();
+                newrunstate = RunState::AwaitingInput;
+            }
+            RunState::ShowInventory => {
+                let result = gui::show_inventory(self, ctx);
+                match result.0 {
+                    gui::ItemMenuResult::Cancel => newrunstate = RunState::AwaitingInput,
+                    gui::ItemMenuResult::NoResponse => {}
+                    gui::ItemMenuResult::Selected => {
+                        let item_entity = result.1.unwrap();
+                        let is_ranged = self.ecs.read_storage::<Ranged>();
+                        let is_item_ranged = is_ranged.get(item_entity);
+                        if let Some(is_item_ranged) = is_item_ranged {
+                            newrunstate = RunState::ShowTargeting { range: is_item_ranged.range, item: item_entity };
+                        } else {
+                            let mut intent = self.ecs.write_storage::<WantsToUseItem>();
+                            intent.insert(*self.ecs.fetch::<Entity>(), WantsToUseItem { item: item_entity , target : None}).expect("Unable to insert intent");
+                            newrunstate = RunState::PlayerTurn;
+                        }
+                    }
+                }
+            }
+            RunState::ShowDropItem => {
+                let result = gui::drop_item_menu(self, ctx);
+                match result.0 {
+                    gui::ItemMenuResult::Cancel => newrunstate = RunState::AwaitingInput,
+                    gui::ItemMenuResult::NoResponse => {}
+                    gui::ItemMenuResult::Selected => {
+                        let item_entity = result.1.unwrap();
+                        let mut intent = self.ecs.write_storage::<WantsToDropItem>();
+                        intent.insert(*self.ecs.fetch::<Entity>(), WantsToDropItem { item: item_entity }).expect("Unable to insert intent");
+                        newrunstate = RunState::PlayerTurn;
+                    }
+                }
+            } 
+            RunState::ShowTargeting { range, item } => {
+                let result = gui::ranged_target(self, ctx, range);
+                match result.0 {
+                    gui::ItemMenuResult::Cancel => newrunstate = RunState::AwaitingInput,
+                    gui::ItemMenuResult::NoResponse => {}
+                    gui::ItemMenuResult::Selected => {
+                        let mut intent = self.ecs.write_storage::<WantsToUseItem>();
+                        intent.insert(*self.ecs.fetch::<Entity>(), WantsToUseItem { item, target : result.1 }).expect("Unable to insert intent");
+                        newrunstate = RunState::PlayerTurn;
+                    }
+                }
+            }
+            RunState::MainMenu { .. } => {
+                let result = gui::main_menu(self, ctx);
+                match result {
+                    gui::MainMenuResult::NoSelection { selected } => newrunstate = RunState::MainMenu { menu_selection: selected },
+                    gui::MainMenuResult::Selected { selected } => {
+                        match selected {
+                            gui::MainMenuSelection::SinglePlayer => newrunstate = self.start_game(PlayMode::SinglePlayer),
+                            gui::MainMenuSelection::Multiplayer => newrunstate = self.start_game(PlayMode::Multiplayer),
+                            gui::MainMenuSelection::Spectate => newrunstate = self.start_game(PlayMode::Spectator),
+                            gui::MainMenuSelection::SaveGame => {
+                                systems::saveload_system::save_game(&mut self.ecs);
+                                newrunstate = RunState::MainMenu{ menu_selection : gui::MainMenuSelection::Quit };
+                            }
+                            gui::MainMenuSelection::LoadGame => {
+                                if systems::saveload_system::does_save_exist() {
+                                    systems::saveload_system::load_game(&mut self.ecs);
+                                    if self.play_mode.is_none() {
+                                        self.play_mode = Some(PlayMode::SinglePlayer);
+                                    }
+                                    newrunstate = RunState::AwaitingInput;
+                                    systems::saveload_system::delete_save();
+                                } else {
+                                    ctx.print_color_centered(36, RGB::named(rltk::RED), RGB::named(rltk::BLACK), "You don't have saves!!!");
+                                }
+                            }
+                            gui::MainMenuSelection::Rating => {
+                                // the leaderboard is kept by the server, the game connects to it before playing
+                                if self.play_mode.is_none() && !self.config.offline {
+                                    self.connect_for_rating();
+                                }
+                                // the games of the room of the last game are shown first
+                                self.rating.room = self.room.clone();
+                                self.rating.all_rooms = self.room.is_none();
+                                self.rating.requested = false;
+                                newrunstate = RunState::ShowRating;
+                            }
+                            gui::MainMenuSelection::Quit => ::std::process::exit(0),
+                        }
+                    }
+                }
+            }
+            RunState::NextLevel => {
+                self.goto_level(self.current_depth() + 1);
+                newrunstate = RunState::PreRun;
+            }
+            RunState::PreviousLevel => {
+                self.goto_level(self.current_depth() - 1);
+                newrunstate = RunState::PreRun;
+            }
+            RunState::ShowRemoveItem => {
+                let result = gui::remove_item_menu(self, ctx);
+                match result.0 {
+                    gui::ItemMenuResult::Cancel => newrunstate = RunState::AwaitingInput,
+                    gui::ItemMenuResult::NoResponse => {}
+                    gui::ItemMenuResult::Selected => {
+                        let item_entity = result.1.unwrap();
+                        let mut intent = self.ecs.write_storage::<WantsToRemoveItem>();
+                        intent.insert(*self.ecs.fetch::<Entity>(), WantsToRemoveItem {item : item_entity}).expect("Unable to insert intent");
+                        newrunstate = RunState::PlayerTurn;
+                    }
+                }
+            }
+            RunState::Chatting => {
+                match gui::chat_input(ctx, &mut self.chat_text, &mut self.chat_scope) {
+                    gui::ChatResult::NoResponse => {}
+                    gui::ChatResult::Send => {
+                        let depth = match self.chat_scope {
+                            ChatScope::Depth => Some(self.current_depth()),
+                            ChatScope::All => None,
+                        };
+                        let text = std::mem::take(&mut self.chat_text);
+                        self.game_client.send_message(&ProtocolMessage::Chat { name : self.player_name.clone(), text, depth });
+                        newrunstate = RunState::AwaitingInput;
+                    }
+                    gui::ChatResult::Cancel => newrunstate = RunState::AwaitingInput,
+                }
+            }
+            RunState::Spectating => {
+                self.follow_watched();
+                self.run_systems();
+                self.ecs.maintain();
+                match gui::spectating(ctx, self.watched.as_deref()) {
+                    gui::SpectateResult::NoResponse => {}
+                    gui::SpectateResult::Next => self.switch_watched(1),
+                    gui::SpectateResult::Previous => self.switch_watched(-1),
+                    gui::SpectateResult::Leave => newrunstate = self.stop_spectating(),
+                }
+            }
+            RunState::GameOver => {
+                let result = gui::game_over(ctx);
+                match result {
+                    gui::GameOverResult::NoSelection => {}
+                    gui::GameOverResult::QuitToMenu => {
+                        self.game_over_cleanup();
+                        ::std::process::exit(0);
+                    }
+                }
+            }
+        }
+
+        {
+            let mut runwriter = self.ecs.write_resource::<RunState>();
+            *runwriter = newrunstate;
+        }
+
+        self.bury_the_dead();
+        self.game_client.messages.clear();
+    }
+}
+
+
+impl State {
+
+    /// Game with the first level built from the seed of the config, it starts in the main menu
+    /// or right in the single player game if the config is offline
+    pub fn new(config : Config, prefabs : Vec<Prefab>) -> Self {
+        // the server may give another seed when the multiplayer game starts
+        let game_seed = config.seed.unwrap_or_else(|| RandomNumberGenerator::new().next_u64());
+        let mut level_rng = RandomNumberGenerator::seeded(level_seed(game_seed, 1));
+        let level = map_builders::build_level(1, &mut level_rng, &prefabs);
+        let (player_x, player_y) = level.start;
+
+        // initialize game state
+        let mut gs = State{ 
+            ecs : World::new(),
+            game_client : ClientHandler::local(),
+            config,
+            game_seed,
+            play_mode : None,
+            network_error : None,
+            player_name : String::new(),
+            enemies : Vec::<String>::new(),
+            pending : None,
+            resync : None,
+            room : None,
+            room_max_players : None,
+            rooms : Vec::new(),
+            rooms_requested : None,
+            room_form : RoomForm::new(None),
+            chat_text : String::new(),
+            chat_scope : ChatScope::Depth,
+            authorities : HashMap::new(),
+            remote_turn : false,
+            remote_pickups : Vec::new(),
+            remote_drops : Vec::new(),
+            watched : None,
+            players : Vec::new(),
+            players_requested : None,
+            rating : RatingView::default(),
+            known_players : HashMap::new(),
+            password : String::new(),
+            tokens : accounts::TokenStore::load(Path::new(accounts::TOKEN_FILE)),
+            prefabs,
+        };
+
+        // register all components
+        gs.ecs.register::<Position>();
+        gs.ecs.register::<Renderable>();
+        gs.ecs.register::<Player>();
+        gs.ecs.register::<Viewshed>();
+        gs.ecs.register::<Monster>();
+        gs.ecs.register::<Name>();
+        gs.ecs.register::<BlocksTile>();
+        gs.ecs.register::<CombatStats>();
+        gs.ecs.register::<WantsToMelee>();
+        gs.ecs.register::<SufferDamage>();
+        gs.ecs.register::<Item>();
+        gs.ecs.register::<ProvidesHealing>();
+        gs.ecs.register::<InBackpack>();
+        gs.ecs.register::<WantsToPickupItem>();
+        gs.ecs.register::<WantsToUseItem>();
+        gs.ecs.register::<WantsToDropItem>();
+        gs.ecs.register::<Consumable>();
+        gs.ecs.register::<Ranged>();
+        gs.ecs.register::<InflictDamage>();
+        gs.ecs.register::<AreaOfEffect>();
+        gs.ecs.register::<Confusion>();
+        gs.ecs.register::<SimpleMarker<SerializeMe>>();
+        gs.ecs.register::<SerializationHelper>();
+        gs.ecs.register::<Equippable>();
+        gs.ecs.register::<Equipped>();
+        gs.ecs.register::<MeleePowerBonus>();
+        gs.ecs.register::<DefenseBonus>();
+        gs.ecs.register::<WantsToRemoveItem>();
+        gs.ecs.register::<Enemy>();
+        gs.ecs.register::<RemoteMotion>();
+        gs.ecs.register::<NetworkId>();
+        gs.ecs.register::<OtherLevelPosition>();
+        
+        gs.ecs.insert(SimpleMarkerAllocator::<SerializeMe>::new());
+
+        // insert player entity to the game state
+        let player_entity = spawner::player(&mut gs.ecs, player_x, player_y);
+        match gs.config.seed {
+            Some(seed) => gs.ecs.insert(RandomNumberGenerator::seeded(seed)),
+            None => gs.ecs.insert(RandomNumberGenerator::new()),
+        }
+        gs.ecs.insert(gs.config.tiles);
+        gs.ecs.insert(GameMode::Coop);
+        gs.ecs.insert(RunStats::default());
+        gs.ecs.insert(MoveSequence::default());
+        gs.ecs.insert(LevelStore::default());
+
+        for region in level.spawn_regions.iter() {
+            spawn_region(&mut gs.ecs, region, 1, &mut level_rng);
+        }
+        for (idx, name) in level.spawns.iter() {
+            spawn_on_tile(&mut gs.ecs, name, *idx, 1);
+        }
+
+        gs.ecs.insert(level.map);
+        gs.ecs.insert(Point::new(player_x, player_y));
+        gs.ecs.insert(player_entity);
+
+        // the offline game doesn't need the choice in the main menu
+        let runstate = if gs.config.offline {
+            gs.start_game(PlayMode::SinglePlayer)
+        } else {
+            RunState::MainMenu { menu_selection : MainMenuSelection::SinglePlayer }
+        };
+        gs.ecs.insert(runstate);
+        gs.ecs.insert(GameLog { entries : vec!["Welcome to Rusty Roguelike".to_string()], colors : HashMap::new() });
+        gs
+    }
+
+    /// Take the messages of the server and apply the ones about the other players and the shared world,
+    /// the replies to the requests are left for the state machine
+    pub fn receive_messages(&mut self) {
+        self.game_client.get_messages();
+        if let Some(reason) = self.game_client.rejection.take() {
+            self.reject(reason);
+        }
+        self.ecs.insert(self.game_client.status());
+        if self.game_client.take_reconnected() {
+            self.announce();
+        }
+        self.poll_resync();
+
+        self.drop_stale_moves();
+        self.delete_enemies();
+        self.remove_departed_enemies();
+        self.update_health_enemies();
+        self.apply_world_events();
+        self.receive_chat();
+        self.receive_rejections();
+    }
+
+    /// Delete the dead, other players learn about the death of the player
+    pub fn bury_the_dead(&mut self) {
+        let was_over = *self.ecs.fetch::<RunState>() == RunState::GameOver;
+        systems::damage_system::delete_the_dead(&mut self.ecs);
+
+        if !was_over && *self.ecs.fetch::<RunState>() == RunState::GameOver {
+            let stats = (*self.ecs.fetch::<RunStats>()).clone();
+            let depth = self.current_depth();
+            self.game_client.send_message(&ProtocolMessage::Died {
+                name : self.player_name.clone(),
+                depth,
+                max_depth : stats.max_depth.max(depth),
+                kills : stats.kills,
+                turns : stats.turns,
+                cause : stats.cause,
+            });
+        }
+    }
+
+    /// void all game systems
+    pub fn run_systems(&mut self) {
+        let mut vis = VisibilitySystem{};
+        vis.run_now(&self.ecs);
+
+        let enemies_pos = self.get_enemies_pos();
+        let mut en = EnemySystem{enemies_pos};
+        en.run_now(&self.ecs);
+
+        let is_authority = self.is_authority();
+        let mut mob = MonsterAI{ is_authority, game_client : &mut self.game_client };
+        mob.run_now(&self.ecs);
+        let mut mapindex = MapIndexingSystem {};
+        mapindex.run_now(&self.ecs);
+        let mut melee = MeleeCombatSystem{};
+        melee.run_now(&self.ecs);
+
+        let mut damage = DamageSystem{ is_authority, game_client : &mut self.game_client };
+        damage.run_now(&self.ecs);
+
+        let remote_pickups = std::mem::take(&mut self.remote_pickups);
+        let mut pickup = ItemCollectSystem{ remote_pickups, game_client : &mut self.game_client };
+        pickup.run_now(&self.ecs);
+        let mut potions = ItemUseSystem{ is_authority, game_client : &mut self.game_client };
+        potions.run_now(&self.ecs);
+        let remote_drops = std::mem::take(&mut self.remote_drops);
+        let mut drop_items = ItemDropSystem{ remote_drops, game_client : &mut self.game_client };
+        drop_items.run_now(&self.ecs);
+        let mut item_remove = ItemRemoveSystem {};
+        item_remove.run_now(&self.ecs);
+
+        self.ecs.maintain();
+
+    }
+
+    /// Handles enemy movements, spawns it if it was not in the enemy vector
+    pub fn get_enemies_pos(&mut self) -> Vec<(String, i32)> {
+        // get current depth
+        let current_depth;
+        {
+            let worldmap = self.ecs.read_resource::<Map>();
+            current_depth = worldmap.depth;
+        }
+
+        let mut enemies_pos = Vec::<(String, i32)>::new();
+
+        let moves = self.game_client.messages.iter().filter_map(|m| match m {
+            ProtocolMessage::Move { name, idx, depth, .. } => Some((name.clone(), *idx, *depth)),
+            _ => None,
+        }).collect::<Vec<_>>();
+
+        for (name, idx, level) in moves {
+            if current_depth != level || name == self.player_name || self.watched.as_ref() == Some(&name) {
+                continue;
+            }
+
+            // the server checks the steps, a move into the wall is a sign of a broken game
+            if validation::check_move(&self.ecs.fetch::<Map>(), None, idx).is_err() {
+                continue;
+            }
+
+            if self.enemies.contains(&name) {
+                enemies_pos.push((name, idx));
+            } else {
+                self.enemies.push(name.clone());
+                let (x, y) = idx_xy(idx);
+                spawner::enemy(&mut self.ecs, x, y, name);
+            }
+        }
+
+        enemies_pos
+    }
+
+    /// Remember the last place of every other player and drop the moves that have come after the newer ones.
+    /// The messages go in order, so the player that has died or left starts the numbers anew
+    fn drop_stale_moves(&mut self) {
+        let known_players = &mut self.known_players;
+        let player_name = &self.player_name;
+        self.game_client.messages.retain(|msg| match msg {
+            ProtocolMessage::Move { name, .. } if name == player_name => true,
+            ProtocolMessage::Move { name, idx, depth, seq } => {
+                let fresh = known_players.get(name).is_none_or(|known| *seq > known.seq);
+                if fresh {
+                    known_players.insert(name.clone(), KnownPlayer { depth : Some(*depth), idx : *idx, seq : *seq });
+                }
+                fresh
+            }
+            ProtocolMessage::Died { name, .. } | ProtocolMessage::Left { name } => {
+                known_players.remove(name);
+                true
+            }
+            // the player is somewhere else now, the next move tells where
+            ProtocolMessage::Change { name, depth } => {
+                if let Some(known) = known_players.get_mut(name).filter(|known| known.depth == Some(*depth)) {
+                    known.depth = None;
+                }
+                true
+            }
+            _ => true,
+        });
+    }
+
+    /// Spawn the other players that are known to be on the depth, they don't have to move to be seen
+    fn spawn_known_players(&mut self, depth : i32) {
+        let known = {
+            let map = self.ecs.fetch::<Map>();
+            self.known_players.iter()
+                .filter(|(name, known)| known.depth == Some(depth) && **name != self.player_name && self.watched.as_ref() != Some(*name))
+                .filter(|(name, known)| !self.enemies.contains(*name) && validation::check_move(&map, None, known.idx).is_ok())
+                .map(|(name, known)| (name.clone(), known.idx))
+                .collect::<Vec<_>>()
+        };
+
+        for (name, idx) in known {
+            self.enemies.push(name.clone());
+            let (x, y) = idx_xy(idx);
+            spawner::enemy(&mut self.ecs, x, y, name);
+        }
+    }
+
+    /// Removes all enemies when they change level
+    pub fn delete_enemies(&mut self) {
+        let to_remove = self.game_client.messages.iter().filter_map(|m| match m {
+            ProtocolMessage::Change { name, depth } => Some((name.clone(), *depth)),
+            _ => None,
+        }).collect::<Vec<_>>();
+
+        let mut to_delete = Vec::<Entity>::new();
+
+        let current_depth;
+        {
+            let worldmap = self.ecs.read_resource::<Map>();
+            current_depth = worldmap.depth;
+        }
+
+        if !to_remove.is_empty() {
+            let enemies = self.ecs.read_storage::<Enemy>();
+            let names = self.ecs.read_storage::<Name>();
+            
+            let ents = enemies.fetched_entities();
+
+            for e in ents.join() {
+                let name = names.get(e).expect("Can't get name");
+                if to_remove.iter().any(|(_name, _level)| *_name == *name.name && *_level == current_depth) {
+                    to_delete.push(e);
+                    if let Some(index) = self.enemies.iter().position(|x| *x == *name.name) {
+                        self.enemies.remove(index);
+                    }
+                }
+            }
+        }
+
+        for e in to_delete {
+            self.ecs.delete_entity(e).expect("Can't delete enemy on level change");
+        }
+    }
+
+    /// Players that have died leave corpses, the disconnected ones are removed
+    pub fn remove_departed_enemies(&mut self) {
+        let departed = self.game_client.messages.iter().filter_map(|m| match m {
+            ProtocolMessage::Died { name, .. } => Some((name.clone(), true)),
+            ProtocolMessage::Left { name } => Some((name.clone(), false)),
+            _ => None,
+        }).collect::<Vec<_>>();
+
+        for (name, died) in departed {
+            if name == self.player_name {
+                continue;
+            }
+
+            let enemy = {
+                let entities = self.ecs.entities();
+                let enemies = self.ecs.read_storage::<Enemy>();
+                let names = self.ecs.read_storage::<Name>();
+                (&entities, &enemies, &names).join()
+                    .find(|(_, _, enemy_name)| enemy_name.name == name)
+                    .map(|(entity, _, _)| entity)
+            };
+            self.enemies.retain(|enemy_name| *enemy_name != name);
+
+            let mut gamelog = self.ecs.fetch_mut::<GameLog>();
+            if died {
+                gamelog.entries.push(format!("{} has died", name));
+            } else {
+                gamelog.entries.push(format!("{} has left the game", name));
+            }
+            drop(gamelog);
+
+            let enemy = match enemy {
+                Some(enemy) => enemy,
+                None => continue,
+            };
+            if died {
+                spawner::corpse(&mut self.ecs, enemy, &name);
+            } else {
+                self.ecs.delete_entity(enemy).expect("Can't delete the enemy that has left");
+            }
+        }
+    }
+
+    /// Update the health of the players from other computers
+    pub fn update_health_enemies(&mut self) {
+        let to_update = self.game_client.messages.iter().filter_map(|m| match m {
+            // the own hp is counted by this game, the hp above the maximum is impossible
+            ProtocolMessage::Damage { name, hp } if *name != self.player_name && validation::check_hp(*hp, PLAYER_MAX_HP).is_ok() => {
+                Some((name.clone(), *hp))
+            }
+            _ => None,
+        }).collect::<Vec<_>>();
+        
+        if !to_update.is_empty() {
+            let entities = self.ecs.entities();
+            let names = self.ecs.read_storage::<Name>();
+            let enemies = self.ecs.read_storage::<Enemy>();
+            let mut combat_stats = self.ecs.write_storage::<CombatStats>();
+
+            // only the players have the reported hp, the monsters with the same name keep theirs
+            for (e, name, _) in (&entities, &names, &enemies).join() {
+                if let Some(enemy) = to_update.iter().find(|(_name, _)| *_name == name.name) {
+                    if let Some(old_health) = combat_stats.get_mut(e) {
+                        old_health.hp = enemy.1;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Whether this game simulates the monsters of the current depth.
+    /// The offline game and the first player on the depth are the authority
+    pub fn is_authority(&self) -> bool {
+        if self.play_mode == Some(PlayMode::Spectator) {
+            return false;
+        }
+        if self.play_mode != Some(PlayMode::Multiplayer) || self.game_client.status() != ConnectionStatus::Connected {
+            return true;
+        }
+        self.authorities.get(&self.current_depth()).is_none_or(|name| *name == self.player_name)
+    }
+
+    /// Apply the events of the shared monsters and items of the current depth
+    fn apply_world_events(&mut self) {
+        let current_depth = self.current_depth();
+        let is_authority = self.is_authority();
+        let events = self.game_client.messages.iter()
+            .filter(|m| match m {
+                ProtocolMessage::Authority { .. } => true,
+                ProtocolMessage::EntityMove { depth, .. } | ProtocolMessage::EntityHp { depth, .. }
+                | ProtocolMessage::ItemPickup { depth, .. } | ProtocolMessage::ItemDrop { depth, .. }
+                | ProtocolMessage::HitEntity { depth, .. } | ProtocolMessage::EntityConfusion { depth, .. }
+                | ProtocolMessage::HitPlayer { depth, .. } | ProtocolMessage::Swap { depth, .. } | ProtocolMessage::Turn { depth }
+                | ProtocolMessage::WorldRequest { depth } | ProtocolMessage::World { depth, .. } => *depth == current_depth,
+                _ => false,
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for event in events {
+            match event {
+                ProtocolMessage::Authority { depth, name } => {
+                    self.authorities.insert(depth, name);
+                }
+                // a tile out of the level would break every system that looks at it, nobody stands in the walls
+                ProtocolMessage::EntityMove { idx, .. } | ProtocolMessage::ItemDrop { idx, .. } | ProtocolMessage::Swap { idx, .. }
+                    if validation::check_move(&self.ecs.fetch::<Map>(), None, idx).is_err() => {}
+                ProtocolMessage::EntityMove { id, idx, .. } if !is_authority => {
+                    if let Some(entity) = self.find_shared(id) {
+                        let (x, y) = idx_xy(idx);
+                        if let Some(pos) = self.ecs.write_storage::<Position>().get_mut(entity) {
+                            pos.x = x;
+                            pos.y = y;
+                        }
+                        if let Some(viewshed) = self.ecs.write_storage::<Viewshed>().get_mut(entity) {
+                            viewshed.dirty = true;
+                        }
+                    }
+                }
+                // the dead are removed at the end of the tick
+                ProtocolMessage::EntityHp { id, hp, .. } if !is_authority => {
+                    if let Some(entity) = self.find_shared(id) {
+                        if let Some(stats) = self.ecs.write_storage::<CombatStats>().get_mut(entity) {
+                            stats.hp = hp;
+                        }
+                    }
+                }
+                // the inventory systems apply the items of other players
+                ProtocolMessage::ItemPickup { id, name, .. } if name != self.player_name => self.remote_pickups.push(id),
+                ProtocolMessage::ItemDrop { id, item, idx, name, .. } if name != self.player_name => {
+                    if self.find_shared(id).is_none() {
+                        let (x, y) = idx_xy(idx);
+                        if let Some(entity) = spawner::spawn_named(&mut self.ecs, &item, x, y) {
+                            self.ecs.write_storage::<NetworkId>()
+                                .insert(entity, NetworkId { id })
+                                .expect("Unable to insert network id");
+                        }
+                    }
+                    self.remote_drops.push((id, idx, name));
+                }
+                ProtocolMessage::HitEntity { id, amount, .. } if is_authority => {
+                    if let Some(entity) = self.find_shared(id) {
+                        SufferDamage::new_damage(&mut self.ecs.write_storage::<SufferDamage>(), entity, amount, None);
+                    }
+                }
+                ProtocolMessage::EntityConfusion { id, turns, .. } if is_authority => {
+                    if let Some(entity) = self.find_shared(id) {
+                        self.ecs.write_storage::<Confusion>()
+                            .insert(entity, Confusion { turns })
+                            .expect("Unable to insert status");
+                    }
+                }
+                ProtocolMessage::HitPlayer { name, amount, attacker, attacker_kind, .. } if name == self.player_name => {
+                    // only the monsters hurt the players of the co-op room, even a modified game can't
+                    if attacker_kind == AttackerKind::Player && *self.ecs.fetch::<GameMode>() == GameMode::Coop {
+                        continue;
+                    }
+
+                    let player_entity = *self.ecs.fetch::<Entity>();
+                    let attacker = Some((attacker, attacker_kind)).filter(|(attacker, _)| !attacker.is_empty());
+                    SufferDamage::new_damage(&mut self.ecs.write_storage::<SufferDamage>(), player_entity, amount, attacker);
+                    let mut gamelog = self.ecs.fetch_mut::<GameLog>();
+                    gamelog.entries.push(format!("You are hit for {} hp.", amount));
+                }
+                ProtocolMessage::Swap { name, idx, depth } if name == self.player_name => {
+                    self.place_player(idx);
+
+                    // everybody else learns the new place from the player's own move
+                    send_move(&self.ecs, &mut self.game_client, &self.player_name, idx, depth);
+                    let mut gamelog = self.ecs.fetch_mut::<GameLog>();
+                    gamelog.entries.push("An ally swaps places with you".to_string());
+                }
+                ProtocolMessage::Turn { .. } if is_authority => self.remote_turn = true,
+                ProtocolMessage::WorldRequest { depth } if is_authority => {
+                    let entities = self.shared_entities();
+                    self.game_client.send_message(&ProtocolMessage::World { depth, entities, stored : false, packed : None });
+                }
+                ProtocolMessage::World { entities, stored, .. } if stored || !is_authority => self.load_shared_entities(entities),
+                _ => {}
+            }
+        }
+    }
+
+    /// Put the player on the tile with the given index
+    fn place_player(&mut self, idx : i32) {
+        let player_entity = *self.ecs.fetch::<Entity>();
+        let (x, y) = idx_xy(idx);
+        if let Some(pos) = self.ecs.write_storage::<Position>().get_mut(player_entity) {
+            pos.x = x;
+            pos.y = y;
+        }
+        if let Some(viewshed) = self.ecs.write_storage::<Viewshed>().get_mut(player_entity) {
+            viewshed.dirty = true;
+        }
+        *self.ecs.write_resource::<Point>() = Point::new(x, y);
+    }
+
+    /// Find the shared monster or item by its network id, the ones frozen on the left levels are not looked at
+    fn find_shared(&self, id : u64) -> Option<Entity> {
+        let entities = self.ecs.entities();
+        let network_ids = self.ecs.read_storage::<NetworkId>();
+        let other_level_positions = self.ecs.read_storage::<OtherLevelPosition>();
+        (&entities, &network_ids, !&other_level_positions).join()
+            .find(|(_, network_id, _)| network_id.id == id)
+            .map(|(entity, _, _)| entity)
+    }
+
+    /// State of all shared monsters and items lying on the current level
+    fn shared_entities(&self) -> Vec<SharedEntity> {
+        let network_ids = self.ecs.read_storage::<NetworkId>();
+        let positions = self.ecs.read_storage::<Position>();
+        let names = self.ecs.read_storage::<Name>();
+        let combat_stats = self.ecs.read_storage::<CombatStats>();
+
+        (&network_ids, &positions, &names, combat_stats.maybe()).join()
+            .map(|(network_id, pos, name, stats)| SharedEntity {
+                id : network_id.id,
+                name : name.name.clone(),
+                idx : xy_idx(pos.x, pos.y) as i32,
+                hp : stats.map(|stats| stats.hp),
+            })
+            .collect()
+    }
+
+    /// Make the shared monsters and items of the level the same as the authority has
+    fn load_shared_entities(&mut self, shared : Vec<SharedEntity>) {
+        // the killed monsters and the picked up items
+        let gone = {
+            let entities = self.ecs.entities();
+            let network_ids = self.ecs.read_storage::<NetworkId>();
+            let positions = self.ecs.read_storage::<Position>();
+            (&entities, &network_ids, &positions).join()
+                .filter(|(_, network_id, _)| !shared.iter().any(|s| s.id == network_id.id))
+                .map(|(entity, _, _)| entity)
+                .collect::<Vec<_>>()
+        };
+        for entity in gone {
+            self.ecs.delete_entity(entity).expect("Unable to delete shared entity");
+        }
+
+        for state in shared {
+            let (x, y) = idx_xy(state.idx);
+            let entity = match self.find_shared(state.id) {
+                Some(entity) => entity,
+                None => match spawner::spawn_named(&mut self.ecs, &state.name, x, y) {
+                    Some(entity) => {
+                        self.ecs.write_storage::<NetworkId>()
+                            .insert(entity, NetworkId { id : state.id })
+                            .expect("Unable to insert network id");
+                        entity
+                    }
+                    None => continue,
+                },
+            };
+
+            if let Some(pos) = self.ecs.write_storage::<Position>().get_mut(entity) {
+                pos.x = x;
+                pos.y = y;
+            }
+            if let (Some(hp), Some(stats)) = (state.hp, self.ecs.write_storage::<CombatStats>().get_mut(entity)) {
+                stats.hp = hp;
+            }
+        }
+    }
+
+    // Return all entities that should be remove on level change
+    fn entities_to_remove_on_level_change(&mut self) -> Vec<Entity> {
+        let entities = self.ecs.entities();
+        let player = self.ecs.read_storage::<Player>();
+        let backpack = self.ecs.read_storage::<InBackpack>();
+        let player_entity = self.ecs.fetch::<Entity>();
+        let equipped = self.ecs.read_storage::<Equipped>();
+        let other_level_positions = self.ecs.read_storage::<OtherLevelPosition>();
+
+        let mut to_delete : Vec<Entity> = Vec::new();
+        for entity in entities.join() {
+            let mut should_delete = true;
+
+            // don't delete the monsters and items of the left levels
+            if other_level_positions.contains(entity) {
+                should_delete = false;
+            }
+
+            // don't delete the player
+            let p = player.get(entity);
+            if let Some(_p) = p {
+                should_delete = false;
+            }
+
+            // don't delete the player's equipment
+            let bp = backpack.get(entity);
+            if let Some(bp) = bp {
+                if bp.owner == *player_entity {
+                    should_delete = false;
+                }
+            }
+
+            let eq = equipped.get(entity);
+            if let Some(eq) = eq {
+                if eq.owner == *player_entity {
+                    should_delete = false;
+                }
+            }
+
+            if should_delete {
+                to_delete.push(entity);
+            }
+        }
+
+        to_delete
+    }
+
+    /// Show the chat lines of other players in the log, the server sends the own lines back too
+    fn receive_chat(&mut self) {
+        let current_depth = self.current_depth();
+        let mut gamelog = self.ecs.fetch_mut::<GameLog>();
+
+        for msg in self.game_client.messages.iter() {
+            if let ProtocolMessage::Chat { name, text, depth } = msg {
+                let scope = match depth {
+                    None => ChatScope::All,
+                    Some(depth) if *depth == current_depth => ChatScope::Depth,
+                    Some(_) => continue,
+                };
+                gamelog.push_colored(format!("[{}] {}: {}", scope.label(), name, text), scope.color());
+            }
+        }
+    }
+
+    /// Show why the server has refused the messages of this game
+    fn receive_rejections(&mut self) {
+        let mut gamelog = self.ecs.fetch_mut::<GameLog>();
+
+        for msg in self.game_client.messages.iter() {
+            if let ProtocolMessage::Rejected { reason, .. } = msg {
+                gamelog.push_colored(format!("The server has refused: {}", reason), RGB::named(rltk::RED));
+            }
+        }
+    }
+
+    /// Keep the list of the open rooms fresh while the player is in the lobby
+    fn refresh_rooms(&mut self) {
+        if let Some(rooms) = self.game_client.messages.iter().find_map(|m| match m {
+            ProtocolMessage::Rooms { rooms } => Some(rooms.clone()),
+            _ => None,
+        }) {
+            self.rooms = rooms;
+        }
+
+        let expired = self.rooms_requested
+            .is_none_or(|requested| requested.elapsed() >= Duration::from_millis(ROOMS_REFRESH_MS));
+        if expired {
+            self.game_client.send_message(&ProtocolMessage::Rooms { rooms : Vec::new() });
+            self.rooms_requested = Some(Instant::now());
+        }
+    }
+
+    /// Start to watch the room. The own player isn't in the world, it is only the eyes of the watched one
+    fn start_spectating(&mut self) -> RunState {
+        let player_entity = *self.ecs.fetch::<Entity>();
+        self.ecs.write_storage::<CombatStats>().remove(player_entity);
+
+        self.watched = None;
+        self.players.clear();
+        self.players_requested = None;
+        self.goto_level(1);
+        RunState::Spectating
+    }
+
+    /// The server has let the game into the room, the seed of the room is asked for next
+    fn enter_room(&mut self, room : String, mode : GameMode, max_players : Option<u32>) -> RunState {
+        self.room = Some(room);
+        self.room_max_players = max_players;
+        self.network_error = None;
+
+        // the server announces whether the players are allies or rivals
+        self.ecs.insert(mode);
+        let mut gamelog = self.ecs.fetch_mut::<GameLog>();
+        gamelog.entries.push(match mode {
+            GameMode::Coop => "This is a co-op room: players are allies and swap places".to_string(),
+            GameMode::Pvp => "This is a PvP room: players may fight each other".to_string(),
+        });
+
+        // the room has the seed of the levels
+        self.pending = Some(self.game_client.request(ProtocolMessage::Seed { seed : self.config.seed }));
+        RunState::AwaitingSeed
+    }
+
+    /// Stop watching and go back to the main menu, the own game starts with a new player
+    fn stop_spectating(&mut self) -> RunState {
+        self.game_client = ClientHandler::local();
+        self.play_mode = None;
+        self.pending = None;
+        self.room = None;
+        self.watched = None;
+        self.players.clear();
+
+        let player_entity = *self.ecs.fetch::<Entity>();
+        let position = *self.ecs.fetch::<Point>();
+        self.ecs.delete_entity(player_entity).expect("Unable to delete the spectator");
+        let player_entity = spawner::player(&mut self.ecs, position.x, position.y);
+        self.ecs.insert(player_entity);
+
+        RunState::MainMenu { menu_selection : MainMenuSelection::Spectate }
+    }
+
+    /// Keep the list of the players fresh and follow the watched one to its depth and tile
+    fn follow_watched(&mut self) {
+        if let Some(players) = self.game_client.messages.iter().find_map(|m| match m {
+            ProtocolMessage::Players { players } => Some(players.clone()),
+            _ => None,
+        }) {
+            self.players = players;
+        }
+
+        let expired = self.players_requested
+            .is_none_or(|requested| requested.elapsed() >= Duration::from_millis(PLAYERS_REFRESH_MS));
+        if expired {
+            self.game_client.send_message(&ProtocolMessage::Players { players : Vec::new() });
+            self.players_requested = Some(Instant::now());
+        }
+
+        // the watched player has died or left the room, the first one of the others is watched
+        if !self.players.iter().any(|player| self.watched.as_ref() == Some(&player.name)) {
+            let first = self.players.first().map(|player| player.name.clone());
+            self.watch(first);
+        }
+
+        // the moves are newer than the list
+        for msg in self.game_client.messages.iter() {
+            if let ProtocolMessage::Move { name, idx, depth, .. } = msg {
+                if let Some(player) = self.players.iter_mut().find(|player| player.name == *name) {
+                    player.depth = *depth;
+                    player.idx = Some(*idx);
+                }
+            }
+        }
+
+        let watched = match self.players.iter().find(|player| self.watched.as_ref() == Some(&player.name)) {
+            Some(watched) => watched.clone(),
+            None => return,
+        };
+        if watched.depth != self.current_depth() {
+            self.goto_level(watched.depth);
+        }
+        if let Some(idx) = watched.idx {
+            let (x, y) = idx_xy(idx);
+            if *self.ecs.fetch::<Point>() != Point::new(x, y) {
+                self.place_player(idx);
+            }
+        }
+    }
+
+    /// Watch the next or the previous player of the room
+    fn switch_watched(&mut self, step : i32) {
+        if self.players.is_empty() {
+            return;
+        }
+
+        let count = self.players.len() as i32;
+        let current = self.players.iter()
+            .position(|player| self.watched.as_ref() == Some(&player.name))
+            .map_or(0, |i| i as i32);
+        let next = self.players[(current + step).rem_euclid(count) as usize].name.clone();
+        self.watch(Some(next));
+    }
+
+    /// Follow the named player: the previous one becomes one of the others, the new one is shown by the own `@`
+    fn watch(&mut self, name : Option<String>) {
+        if self.watched == name {
+            return;
+        }
+
+        let current_depth = self.current_depth();
+        let previous = self.watched.take().and_then(|previous| self.players.iter().find(|player| player.name == previous).cloned());
+        if let Some(PlayerInfo { name : previous, depth, idx : Some(idx) }) = previous {
+            if depth == current_depth && !self.enemies.contains(&previous) {
+                self.enemies.push(previous.clone());
+                let (x, y) = idx_xy(idx);
+                spawner::enemy(&mut self.ecs, x, y, previous);
+            }
+        }
+
+        if let Some(name) = &name {
+            let enemy = {
+                let entities = self.ecs.entities();
+                let enemies = self.ecs.read_storage::<Enemy>();
+                let names = self.ecs.read_storage::<Name>();
+                (&entities, &enemies, &names).join()
+                    .find(|(_, _, enemy_name)| enemy_name.name == *name)
+                    .map(|(entity, _, _)| entity)
+            };
+            if let Some(enemy) = enemy {
+                self.ecs.delete_entity(enemy).expect("Unable to delete the watched player");
+            }
+            self.enemies.retain(|enemy_name| enemy_name != name);
+
+            let player_entity = *self.ecs.fetch::<Entity>();
+            if let Some(player_name) = self.ecs.write_storage::<Name>().get_mut(player_entity) {
+                player_name.name = name.clone();
+            }
+            let mut gamelog = self.ecs.fetch_mut::<GameLog>();
+            gamelog.entries.push(format!("You are watching {}", name));
+        }
+        self.watched = name;
+    }
+
+    /// Choose the way to play and go to the name entering, the game just goes on if it has already started
+    pub fn start_game(&mut self, mode : PlayMode) -> RunState {
+        if self.play_mode.is_some() {
+            return RunState::PreRun;
+        }
+
+        match mode {
+            PlayMode::SinglePlayer => self.game_client = ClientHandler::local(),
+            PlayMode::Multiplayer | PlayMode::Spectator => {
+                match self.open_client() {
+                    Ok(client) => self.game_client = client,
+                    Err(e) => {
+                        self.network_error = Some(e);
+                        return RunState::MainMenu { menu_selection : MainMenuSelection::Multiplayer };
+                    }
+                }
+            }
+        }
+
+        self.network_error = None;
+        self.play_mode = Some(mode);
+
+        if mode != PlayMode::SinglePlayer {
+            self.pending = Some(ClientHandler::handshake(self.hello()));
+            return RunState::AwaitingHandshake;
+        }
+        self.enter_name()
+    }
+
+    /// Connect to the server only to show its leaderboard, without the server the games of this run are shown
+    fn connect_for_rating(&mut self) {
+        match self.open_client() {
+            Ok(client) => {
+                self.game_client = client;
+                self.network_error = None;
+            }
+            Err(e) => self.network_error = Some(e),
+        }
+    }
+
+    /// Greeting of this game, the server checks that the prefabs are the same as its own
+    fn hello(&self) -> ProtocolMessage {
+        ProtocolMessage::hello(map_builders::prefabs::prefab_hash(&self.prefabs))
+    }
+
+    /// Connect to the server or to the script that stands in for it. The bad network is simulated
+    /// and the traffic is recorded if it is asked for, the recording gets the messages the game sees
+    fn open_client(&self) -> Result<ClientHandler, String> {
+        let mut client = match &self.config.replay {
+            Some(script) => ClientHandler::scripted(Path::new(script))?,
+            None => {
+                let server = self.config.server_url().expect("Address error");
+                ClientHandler::connect(&server, self.hello()).map_err(|e| format!("Can't connect to {}: {}", server, e))?
+            }
+        };
+        if self.config.network.is_active() {
+            client.simulate(self.config.network);
+        }
+        if let Some(record) = &self.config.record {
+            client.record(Path::new(record))?;
+        }
+        Ok(client)
+    }
+
+    /// Go to the name entering, the name from the config skips it
+    fn enter_name(&mut self) -> RunState {
+        match self.config.name.clone() {
+            Some(name) => {
+                self.player_name = name;
+                self.check_name()
+            }
+            None => RunState::EnteringName,
+        }
+    }
+
+    /// The entered name is checked by the server. The saved token logs in to the account of the name,
+    /// without it the player chooses to play as a guest, log in or register
+    fn check_name(&mut self) -> RunState {
+        if self.play_mode == Some(PlayMode::SinglePlayer) {
+            let request = ProtocolMessage::IsName { name : self.player_name.clone(), accepted : false };
+            self.pending = Some(self.game_client.request(request));
+            return RunState::AwaitingNameCheck;
+        }
+
+        match self.tokens.get(&self.config.server, &self.player_name) {
+            Some(token) => {
+                let request = ProtocolMessage::Login {
+                    name : self.player_name.clone(),
+                    password : None,
+                    token : Some(token.to_string()),
+                    register : false,
+                    error : None,
+                };
+                self.pending = Some(self.game_client.request(request));
+                RunState::AwaitingLogin
+            }
+            None => RunState::ChoosingAccount { selection : gui::AccountSelection::Guest },
+        }
+    }
+
+    /// The server has answered the check of the name, the refused name is entered again
+    pub fn name_checked(&mut self, reply : ProtocolMessage) -> RunState {
+        if let ProtocolMessage::IsName { accepted : true, .. } = reply {
+            return self.name_accepted();
+        }
+
+        let mut gamelog = self.ecs.fetch_mut::<GameLog>();
+        gamelog.entries.push("This name is used or registered. Please enter another or log in".to_string());
+        self.player_name.clear();
+        RunState::EnteringName
+    }
+
+    /// The name is taken for this player, the multiplayer game goes to the lobby to choose the room first
+    fn name_accepted(&mut self) -> RunState {
+        self.apply_player_name();
+
+        if self.play_mode == Some(PlayMode::Multiplayer) {
+            self.rooms_requested = None;
+            RunState::Lobby { selection : 0 }
+        } else {
+            self.pending = Some(self.game_client.request(ProtocolMessage::Seed { seed : self.config.seed }));
+            RunState::AwaitingSeed
+        }
+    }
+
+    /// The server has refused to play with this client.
+    /// The game goes on offline if it has already started
+    fn reject(&mut self, reason : String) {
+        self.game_client = ClientHandler::local();
+        self.pending = None;
+        self.resync = None;
+        self.room = None;
+
+        if self.play_mode == Some(PlayMode::Spectator) {
+            let runstate = self.stop_spectating();
+            *self.ecs.write_resource::<RunState>() = runstate;
+            self.network_error = Some(reason);
+            return;
+        }
+
+        let mut runstate = self.ecs.write_resource::<RunState>();
+        if *runstate == RunState::AwaitingHandshake || self.play_mode.is_none() {
+            let menu_selection = match *runstate {
+                RunState::ShowRating => MainMenuSelection::Rating,
+                _ => MainMenuSelection::Multiplayer,
+            };
+            self.play_mode = None;
+            *runstate = RunState::MainMenu { menu_selection };
+        } else {
+            self.play_mode = Some(PlayMode::SinglePlayer);
+            let mut gamelog = self.ecs.fetch_mut::<GameLog>();
+            gamelog.entries.push("The server has closed the connection, the game goes on offline".to_string());
+        }
+        self.network_error = Some(reason);
+    }
+
+    /// Returns the depth of the current level
+    pub fn current_depth(&self) -> i32 {
+        self.ecs.read_resource::<Map>().depth
+    }
+
+    /// Polls the pending request and draws the waiting box.
+    /// `None` means that the player has cancelled the request
+    fn wait_for_reply(&mut self, ctx : &mut Rltk, title : &str) -> Option<Reply> {
+        let reply = match self.pending.as_ref() {
+            Some(pending) => pending.poll(&mut self.game_client),
+            None => return None,
+        };
+
+        if let Reply::Ready(_) = reply {
+            self.pending = None;
+            return Some(reply);
+        }
+
+        match gui::waiting_for_server(ctx, title, reply == Reply::TimedOut) {
+            gui::WaitingResult::NoResponse => Some(reply),
+            gui::WaitingResult::Retry => {
+                if let Some(pending) = self.pending.as_mut() {
+                    pending.retry(&mut self.game_client);
+                }
+                Some(Reply::Waiting)
+            }
+            gui::WaitingResult::Cancel => {
+                self.pending = None;
+                None
+            }
+        }
+    }
+
+    /// Tell the server about the player again after the connection has been restored.
+    /// The server may have been restarted, so it is offered the seed of this game
+    fn announce(&mut self) {
+        // the reply to the request may have been lost with the connection
+        if let Some(pending) = self.pending.as_mut() {
+            pending.retry(&mut self.game_client);
+            return;
+        }
+
+        match *self.ecs.fetch::<RunState>() {
+            RunState::EnteringName | RunState::AwaitingNameCheck | RunState::ChoosingAccount { .. } | RunState::EnteringPassword { .. }
+            | RunState::AwaitingLogin | RunState::Lobby { .. } | RunState::CreatingRoom => return,
+            _ => {}
+        }
+        if self.play_mode.is_none() {
+            return;
+        }
+
+        // the spectator watches the room again from the same depth
+        if self.play_mode == Some(PlayMode::Spectator) {
+            if let Some(room) = self.room.clone() {
+                self.game_client.send_message(&ProtocolMessage::JoinRoom { room, spectator : true, seed : None, mode : None, max_players : None, error : None });
+            }
+            self.game_client.send_message(&ProtocolMessage::WorldRequest { depth : self.current_depth() });
+            self.players_requested = None;
+            return;
+        }
+
+        // the server may have lost the room with the restart, the seed restores it
+        if let Some(room) = self.room.clone() {
+            let mode = Some(*self.ecs.fetch::<GameMode>());
+            let max_players = self.room_max_players;
+            self.game_client.send_message(&ProtocolMessage::JoinRoom { room, spectator : false, seed : Some(self.game_seed), mode, max_players, error : None });
+        }
+
+        // the registered name is taken back with the saved login before the server hears of the player
+        if let Some(token) = self.tokens.get(&self.config.server, &self.player_name) {
+            let token = Some(token.to_string());
+            self.game_client.send_message(&ProtocolMessage::Login { name : self.player_name.clone(), password : None, token, register : false, error : None });
+        }
+
+        let depth = self.current_depth();
+        self.game_client.send_message(&ProtocolMessage::TrackMe { name : self.player_name.clone(), depth });
+        self.resync = Some(self.game_client.request(ProtocolMessage::Seed { seed : Some(self.game_seed) }));
+
+        let mut gamelog = self.ecs.fetch_mut::<GameLog>();
+        gamelog.entries.push("Connection to the server is restored".to_string());
+    }
+
+    /// Wait for the seed requested after the reconnect, the local game goes on meanwhile
+    fn poll_resync(&mut self) {
+        let reply = match self.resync.as_ref() {
+            Some(resync) => resync.poll(&mut self.game_client),
+            None => return,
+        };
+
+        match reply {
+            Reply::Waiting => {}
+            Reply::Ready(ProtocolMessage::Seed { seed : Some(seed) }) if seed != self.game_seed => {
+                self.resync = None;
+                self.game_seed = seed;
+                let mut gamelog = self.ecs.fetch_mut::<GameLog>();
+                gamelog.entries.push("Other players are in another world, the next levels will be theirs".to_string());
+            }
+            _ => self.resync = None,
+        }
+    }
+
+    /// Set the entered name to the player entity
+    fn apply_player_name(&mut self) {
+        let player = self.ecs.fetch::<Entity>();
+        let mut names = self.ecs.write_storage::<Name>();
+
+        if let Some(name) = names.get_mut(*player) {
+            name.name = self.player_name.clone();
+        }
+    }
+
+    /// Moves to the level of the given depth. A new level is built from the game seed,
+    /// so every player gets the same one without loading it from the server.
+    /// The left level is kept and comes back as it was, the same depth means that the world starts over
+    pub fn goto_level(&mut self, depth : i32) {
+        let previous_depth = self.current_depth();
+        let is_new_level = previous_depth != depth;
+        {
+            let mut stats = self.ecs.write_resource::<RunStats>();
+            stats.max_depth = stats.max_depth.max(depth);
+        }
+        // the spectator gets the monsters and items from the authority every time
+        let keeps_levels = self.play_mode != Some(PlayMode::Spectator);
+
+        if is_new_level && keeps_levels {
+            // the players that come to the empty level later get it from the server as it is left
+            if self.play_mode == Some(PlayMode::Multiplayer) && self.is_authority() {
+                let entities = self.shared_entities();
+                self.game_client.send_message(&ProtocolMessage::World { depth : previous_depth, entities, stored : false, packed : None });
+            }
+            level_store::freeze_entities(&mut self.ecs, previous_depth);
+            let map = (*self.ecs.fetch::<Map>()).clone();
+            self.ecs.write_resource::<LevelStore>().store(map);
+        } else if !is_new_level {
+            level_store::forget_levels(&mut self.ecs);
+        }
+
+        // delete entities that are not the player or his/her equipment
+        let to_delete = self.entities_to_remove_on_level_change();
+        for target in to_delete {
+            self.ecs.delete_entity(target).expect("Unable to delete entity");
+        }
+        self.remote_pickups.clear();
+        self.remote_drops.clear();
+
+        // take the kept level or build a new map
+        let stored = self.ecs.write_resource::<LevelStore>().take(depth).filter(|_| keeps_levels);
+        let is_first_visit = stored.is_none();
+        let (map, start) = match stored {
+            Some(map) => {
+                level_store::thaw_entities(&mut self.ecs, depth);
+                (map, None)
+            }
+            None => {
+                let mut rng = RandomNumberGenerator::seeded(level_seed(self.game_seed, depth));
+                let level = map_builders::build_level(depth, &mut rng, &self.prefabs);
+
+                // spawn monsters and items
+                for region in level.spawn_regions.iter() {
+                    spawner::spawn_region(&mut self.ecs, region, depth, &mut rng);
+                }
+                for (idx, name) in level.spawns.iter() {
+                    spawner::spawn_on_tile(&mut self.ecs, name, *idx, depth);
+                }
+                let start = level.start_idx() as usize;
+                (level.map, Some(start))
+            }
+        };
+
+        // the player comes down to the up stairs and up to the down stairs
+        let stairs = if depth < previous_depth { TileType::DownStairs } else { TileType::UpStairs };
+        let arrival = map.find_tile(stairs).or(start).unwrap_or_default();
+        *self.ecs.write_resource::<Map>() = map;
+
+        // place the player and update resources
+        {
+            let (player_x, player_y) = idx_xy(arrival as i32);
+            let mut player_position = self.ecs.write_resource::<Point>();
+            *player_position = Point::new(player_x, player_y);
+            let mut position_components = self.ecs.write_storage::<Position>();
+            let player_entity = self.ecs.fetch::<Entity>();
+            let player_pos_comp = position_components.get_mut(*player_entity);
+            if let Some(player_pos_comp) = player_pos_comp {
+                player_pos_comp.x = player_x;
+                player_pos_comp.y = player_y;
+            }
+
+            // mark the player's visibility as dirty
+            let mut viewshed_components = self.ecs.write_storage::<Viewshed>();
+            let vs = viewshed_components.get_mut(*player_entity);
+            if let Some(vs) = vs {
+                vs.dirty = true;
+            }
+        }
+
+        if is_new_level {
+            // clear all enemies on previous level, the players already seen on the new one come back
+            self.enemies.clear();
+            self.spawn_known_players(depth);
+        }
+
+        // the spectator isn't in the world, it only asks for the monsters and items of the level
+        if self.play_mode == Some(PlayMode::Spectator) {
+            self.game_client.send_message(&ProtocolMessage::WorldRequest { depth });
+            return;
+        }
+
+        if is_new_level {
+            let player_entity = self.ecs.fetch::<Entity>();
+            let mut gamelog = self.ecs.fetch_mut::<gamelog::GameLog>();
+
+            // notify the player, only the first way down gives them some health
+            if depth < previous_depth {
+                gamelog.entries.push("You climb up to the previous level.".to_string());
+            } else if !is_first_visit {
+                gamelog.entries.push("You descend to the level you have already been on.".to_string());
+            } else {
+                gamelog.entries.push("You descend to the next level, and take a moment to heal.".to_string());
+                let mut player_health_store = self.ecs.write_storage::<CombatStats>();
+                let player_health = player_health_store.get_mut(*player_entity);
+                if let Some(player_health) = player_health {
+                    player_health.hp = i32::max(player_health.hp, player_health.max_hp / 2);
+                }
+            }
+        }
+
+        self.game_client.send_message(&ProtocolMessage::TrackMe { name : self.player_name.clone(), depth });
+        if is_new_level {
+            self.game_client.send_message(&ProtocolMessage::Change { name : self.player_name.clone(), depth : previous_depth });
+        }
+
+        // the players of the depth see the newcomer before the first step
+        send_move(&self.ecs, &mut self.game_client, &self.player_name, arrival as i32, depth);
+    }
+
+    /// delete everything after game over
+    fn game_over_cleanup(&mut self) {
+        // Delete everything
+        let mut to_delete = Vec::new();
+        for e in self.ecs.entities().join() {
+            to_delete.push(e);
+        }
+        
+        for del in to_delete.iter() {
+            self.ecs.delete_entity(*del).expect("Deletion failed");
+        }
+    }
+}
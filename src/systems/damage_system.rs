@@ -1,31 +1,80 @@
 use specs::prelude::*;
-use crate::{CombatStats, SufferDamage, ClientHandler, Player, Name, GameLog, RunState};
+use crate::{CombatStats, SufferDamage, ClientHandler, Player, Name, GameLog, RunState, ProtocolMessage, Map, Enemy, NetworkId, RunStats, AttackerKind};
 
 
-/// Reduction of xp after any hit
+/// Reduction of xp after any hit.
+/// The damage of players from other computers and of the monsters simulated by another game is sent
+/// to their owners, the hp of the own player and of the simulated monsters is sent to everybody.
+/// The killing blows of the player and the cause of the player's death go to the stats of the game
 pub struct DamageSystem<'a> {
-    pub enemies : Vec<String>,
+    pub is_authority : bool,
     pub game_client : &'a mut ClientHandler,
 }
 
 
 impl<'a> System<'a> for DamageSystem<'a> {
+    #[allow(clippy::type_complexity)]
     type SystemData = ( 
+                        ReadExpect<'a, Map>,
+                        ReadExpect<'a, Entity>,
+                        WriteExpect<'a, RunStats>,
+                        Entities<'a>,
                         WriteStorage<'a, CombatStats>,
                         WriteStorage<'a, SufferDamage>,
                         ReadStorage<'a, Name>,
+                        ReadStorage<'a, Player>,
+                        ReadStorage<'a, Enemy>,
+                        ReadStorage<'a, NetworkId>,
                     );
 
     fn run(&mut self, data : Self::SystemData) {
-        let (mut stats, mut damage, names) = data;
+        let (map, player_entity, mut run_stats, entities, mut stats, mut damage, names, players, enemies, network_ids) = data;
 
-        for (mut stats, damage, name) in (&mut stats, &damage, &names).join() {
-            stats.hp -= damage.amount.iter().sum::<i32>();
+        for (entity, damage, name) in (&entities, &damage, &names).join() {
+            let amount = damage.amount.iter().sum::<i32>();
 
-            // Sending a message to the server to notify the other players of the hp change
-            if self.enemies.iter().find(|&_name| *_name == name.name) != None {
-                let message = format!("{{\"__DAMAGE__\":\"{} {}\"}}", name.name, stats.hp).as_bytes().to_vec();
-                self.game_client.send_message(message);
+            // the hit of the player finishes the victim off, the game that owns the victim agrees with it
+            let killed = stats.get(entity).is_some_and(|stats| stats.hp > 0 && stats.hp <= amount);
+            let by_player = damage.attackers.iter().any(|(_, kind)| *kind == AttackerKind::Player);
+            if entity != *player_entity && killed && by_player {
+                run_stats.kills += 1;
+            }
+
+            // the game of the other player applies the damage itself
+            if enemies.contains(entity) {
+                let (attacker, attacker_kind) = damage.attackers.first().cloned().unwrap_or_default();
+                self.game_client.send_message(&ProtocolMessage::HitPlayer { depth : map.depth, name : name.name.clone(), amount, attacker, attacker_kind });
+                continue;
+            }
+
+
+            let network_id = network_ids.get(entity);
+            if let Some(network_id) = network_id {
+                if !self.is_authority {
+                    self.game_client.send_message(&ProtocolMessage::HitEntity { depth : map.depth, id : network_id.id, amount });
+                    continue;
+                }
+            }
+
+            if let Some(stats) = stats.get_mut(entity) {
+                stats.hp -= amount;
+
+                if entity == *player_entity && stats.hp < 1 && run_stats.cause.is_empty() {
+                    let mut killers = damage.attackers.iter().map(|(name, _)| name.clone()).collect::<Vec<_>>();
+                    killers.dedup();
+                    run_stats.cause = if killers.is_empty() {
+                        "Killed".to_string()
+                    } else {
+                        format!("Killed by {}", killers.join(", "))
+                    };
+                }
+
+                // Sending a message to the server to notify the other players of the hp change
+                if players.contains(entity) {
+                    self.game_client.send_message(&ProtocolMessage::Damage { name : name.name.clone(), hp : stats.hp });
+                } else if let Some(network_id) = network_id {
+                    self.game_client.send_message(&ProtocolMessage::EntityHp { depth : map.depth, id : network_id.id, hp : stats.hp });
+                }
             }
         }
 
@@ -33,7 +82,7 @@ impl<'a> System<'a> for DamageSystem<'a> {
     }
 }
 
-/// Delete all dead entities
+/// Delete all dead entities. Players from other computers announce their death themselves
 pub fn delete_the_dead(ecs : &mut World) {
     let mut dead : Vec<Entity> = Vec::new();
 
@@ -43,8 +92,9 @@ pub fn delete_the_dead(ecs : &mut World) {
         let entities = ecs.entities();
         let names = ecs.read_storage::<Name>();
         let players = ecs.read_storage::<Player>();
+        let enemies = ecs.read_storage::<Enemy>();
         let mut log = ecs.write_resource::<GameLog>();
-        for (entity, stats) in (&entities, &combat_stats).join() {
+        for (entity, stats, _) in (&entities, &combat_stats, !&enemies).join() {
             if stats.hp < 1 {
                 let player = players.get(entity);
                 
@@ -1,9 +1,12 @@
+use std::time::{Duration, Instant};
+
 use specs::prelude::*;
-use crate::{Position, Name, Enemy, idx_xy};
+use crate::{Position, Name, Enemy, RemoteMotion, Map, idx_xy, xy_idx, MOVE_STEP_MS, MAX_MOVE_QUEUE};
 
 
 #[derive(Default)]
-/// Change the position of enemies
+/// Change the position of enemies. The moves are walked one tile at a time,
+/// so a burst of moves after the network lag doesn't look like a teleport
 pub struct EnemySystem {
     pub enemies_pos : Vec<(String, i32)>,
 }
@@ -12,19 +15,39 @@ impl<'a> System<'a> for EnemySystem {
     type SystemData = ( ReadStorage<'a, Name>,
                         ReadStorage<'a, Enemy>,
                         WriteStorage<'a, Position>,
+                        WriteStorage<'a, RemoteMotion>,
+                        ReadExpect<'a, Map>,
                     );
 
     fn run(&mut self, data : Self::SystemData) {
-        let (names, enemy, mut pos) = data;
+        let (names, enemy, mut pos, mut motions, map) = data;
+        let now = Instant::now();
 
-        for (name, _e, p) in (&names, &enemy, &mut pos).join() {
+        for (name, _e, p, motion) in (&names, &enemy, &mut pos, &mut motions).join() {
             for pare in self.enemies_pos.iter() {
                 if pare.0 == name.name {
+                    motion.path.push_back(pare.1);
+                }
+            }
+
+            // the player far behind skips the old steps to catch up
+            while motion.path.len() > MAX_MOVE_QUEUE {
+                motion.path.pop_front();
+            }
+
+            if motion.next_step.is_none_or(|next_step| next_step <= now) {
+                if let Some(idx) = motion.path.pop_front() {
                     // update enemy position
-                    p.x = idx_xy(pare.1).0;
-                    p.y = idx_xy(pare.1).1;
+                    p.x = idx_xy(idx).0;
+                    p.y = idx_xy(idx).1;
+                    motion.next_step = Some(now + Duration::from_millis(MOVE_STEP_MS));
                 }
             }
+
+            let idx = xy_idx(p.x, p.y);
+            if map.visible_tiles[idx] {
+                motion.last_seen = Some(idx as i32);
+            }
         }
     }
 }
@@ -3,24 +3,39 @@ use specs::prelude::*;
 use crate::{WantsToPickupItem, Name, InBackpack, Position, gamelog::GameLog};
 use crate::{WantsToUseItem, ProvidesHealing, CombatStats, WantsToDropItem, Consumable};
 use crate::{SufferDamage, InflictDamage, xy_idx, Map, AreaOfEffect, Confusion, Equippable, Equipped, WantsToRemoveItem};
+use crate::{NetworkId, ClientHandler, ProtocolMessage, idx_xy, Enemy, GameMode, AttackerKind};
 
 
-/// Responsible for picking up objects
-pub struct ItemCollectSystem {}
+/// Responsible for picking up objects, other players are told that the shared item is gone
+/// and the items picked up by them are removed from the floor
+pub struct ItemCollectSystem<'a> {
+    pub remote_pickups : Vec<u64>,
+    pub game_client : &'a mut ClientHandler,
+}
 
 
-impl<'a> System<'a> for ItemCollectSystem {
+impl<'a> System<'a> for ItemCollectSystem<'a> {
     #[allow(clippy::type_complexity)]
     type SystemData = ( ReadExpect<'a, Entity>,
+                        ReadExpect<'a, Map>,
                         WriteExpect<'a, GameLog>,
+                        Entities<'a>,
                         WriteStorage<'a, WantsToPickupItem>,
                         WriteStorage<'a, Position>,
                         ReadStorage<'a, Name>,
-                        WriteStorage<'a, InBackpack>
+                        WriteStorage<'a, InBackpack>,
+                        ReadStorage<'a, NetworkId>,
                     );
     
     fn run(&mut self, data: Self::SystemData) {
-        let (player_entity, mut gamelog, mut wants_pickup, mut position, names, mut backpack) = data;
+        let (player_entity, map, mut gamelog, entities, mut wants_pickup, mut position, names, mut backpack, network_ids) = data;
+
+        // only the items lying on the floor, the one already picked up here stays in the backpack
+        for (entity, network_id, _pos) in (&entities, &network_ids, &position).join() {
+            if self.remote_pickups.contains(&network_id.id) {
+                entities.delete(entity).expect("Unable to delete picked up item");
+            }
+        }
 
         for pickup in wants_pickup.join() {
             position.remove(pickup.item);
@@ -28,21 +43,32 @@ impl<'a> System<'a> for ItemCollectSystem {
 
             if pickup.collected_by == *player_entity {
                 gamelog.entries.push(format!("You pick up the {}.", names.get(pickup.item).unwrap().name));
+
+                if let Some(network_id) = network_ids.get(pickup.item) {
+                    let name = names.get(*player_entity).map(|n| n.name.clone()).unwrap_or_default();
+                    self.game_client.send_message(&ProtocolMessage::ItemPickup { depth : map.depth, id : network_id.id, name });
+                }
             }
         }
         wants_pickup.clear();
     }
 }
 
-/// Responsible for the use of objects and the execution of their effects
-pub struct ItemUseSystem {}
+/// Responsible for the use of objects and the execution of their effects.
+/// The confusion of the shared monsters is sent to the authority of the depth,
+/// the spells of the co-op game spare other players
+pub struct ItemUseSystem<'a> {
+    pub is_authority : bool,
+    pub game_client : &'a mut ClientHandler,
+}
 
 
-impl<'a> System<'a> for ItemUseSystem {
+impl<'a> System<'a> for ItemUseSystem<'a> {
     #[allow(clippy::type_complexity)]
     type SystemData = ( ReadExpect<'a, Entity>,
                         WriteExpect<'a, GameLog>,
                         ReadExpect<'a, Map>,
+                        ReadExpect<'a, GameMode>,
                         Entities<'a>,
                         WriteStorage<'a, WantsToUseItem>,
                         ReadStorage<'a, Name>,
@@ -56,15 +82,17 @@ impl<'a> System<'a> for ItemUseSystem {
                         ReadStorage<'a, Equippable>,
                         WriteStorage<'a, Equipped>,
                         WriteStorage<'a, InBackpack>,
+                        ReadStorage<'a, NetworkId>,
+                        ReadStorage<'a, Enemy>,
                     );
     
     fn run(&mut self, data: Self::SystemData) {
         let (player_entity, mut gamelog, 
-                map , entities, 
+                map, mode, entities, 
                 mut wants_use, names, 
                 consumables, healing, inflict_damage, 
                 mut combat_stats, mut suffer_damage, aoe, 
-                mut confused, equippable, mut equipped, mut backpack) = data;
+                mut confused, equippable, mut equipped, mut backpack, network_ids, enemies) = data;
         
         for (entity, useitem) in (&entities, &wants_use).join() {
             let mut used_item = true;
@@ -98,6 +126,11 @@ impl<'a> System<'a> for ItemUseSystem {
                 }
             }
 
+            // allies are not hit by the spells
+            if useitem.target.is_some() && *mode == GameMode::Coop {
+                targets.retain(|target| !enemies.contains(*target));
+            }
+
             // if it is equippable, then we want to equip it - and unequip whatever else it that slot
             let item_equippable = equippable.get(useitem.item);
             match item_equippable {
@@ -155,7 +188,9 @@ impl<'a> System<'a> for ItemUseSystem {
                 Some(damage) => {
                     used_item = false;
                     for mob in targets.iter() {
-                        SufferDamage::new_damage(&mut suffer_damage, *mob, damage.damage);
+                        let kind = if entity == *player_entity { AttackerKind::Player } else { AttackerKind::Monster };
+                        let attacker = names.get(entity).map(|attacker| (attacker.name.clone(), kind));
+                        SufferDamage::new_damage(&mut suffer_damage, *mob, damage.damage, attacker);
                         if entity == *player_entity {
                             let mob_name = names.get(*mob).unwrap();
                             let item_name = names.get(useitem.item).unwrap();
@@ -186,7 +221,14 @@ impl<'a> System<'a> for ItemUseSystem {
             }
 
             for mob in add_confusion.iter() {
-                confused.insert(mob.0, Confusion { turns: mob.1 }).expect("Unable to insert status");
+                match network_ids.get(mob.0) {
+                    Some(network_id) if !self.is_authority => {
+                        self.game_client.send_message(&ProtocolMessage::EntityConfusion { depth : map.depth, id : network_id.id, turns : mob.1 });
+                    }
+                    _ => {
+                        confused.insert(mob.0, Confusion { turns: mob.1 }).expect("Unable to insert status");
+                    }
+                }
             }
 
             // If its a consumable, we delete it on use
@@ -205,23 +247,37 @@ impl<'a> System<'a> for ItemUseSystem {
 }
 
 
-/// Responsible for the dropping items
-pub struct ItemDropSystem {}
+/// Responsible for the dropping items. The dropped shared items appear for other players,
+/// so the items dropped by them are placed here
+pub struct ItemDropSystem<'a> {
+    pub remote_drops : Vec<(u64, i32, String)>,
+    pub game_client : &'a mut ClientHandler,
+}
 
 
-impl<'a> System<'a> for ItemDropSystem {
+impl<'a> System<'a> for ItemDropSystem<'a> {
     #[allow(clippy::type_complexity)]
     type SystemData = ( ReadExpect<'a, Entity>,
+                        ReadExpect<'a, Map>,
                         WriteExpect<'a, GameLog>,
                         Entities<'a>,
                         WriteStorage<'a, WantsToDropItem>,
                         ReadStorage<'a, Name>,
                         WriteStorage<'a, Position>,
-                        WriteStorage<'a, InBackpack>
+                        WriteStorage<'a, InBackpack>,
+                        ReadStorage<'a, NetworkId>,
                     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (player_entity, mut gamelog, entities, mut wants_drop, names, mut positions, mut backpack) = data;
+        let (player_entity, map, mut gamelog, entities, mut wants_drop, names, mut positions, mut backpack, network_ids) = data;
+
+        // the items unknown to this game have been spawned on the floor, the own ones stay with the player
+        for (network_id, name, pos) in (&network_ids, &names, &mut positions).join() {
+            if let Some((_, idx, dropped_by)) = self.remote_drops.iter().find(|(id, _, _)| *id == network_id.id) {
+                (pos.x, pos.y) = idx_xy(*idx);
+                gamelog.entries.push(format!("{} drops the {}.", dropped_by, name.name));
+            }
+        }
 
         for (entity, to_drop) in (&entities, &wants_drop).join() {
             let mut dropper_pos : Position = Position { x: 0, y: 0 };
@@ -235,7 +291,18 @@ impl<'a> System<'a> for ItemDropSystem {
             backpack.remove(to_drop.item);
 
             if entity == *player_entity {
-                gamelog.entries.push(format!("You drop the {}.", names.get(to_drop.item).unwrap().name));
+                let item_name = &names.get(to_drop.item).unwrap().name;
+                gamelog.entries.push(format!("You drop the {}.", item_name));
+
+                if let Some(network_id) = network_ids.get(to_drop.item) {
+                    self.game_client.send_message(&ProtocolMessage::ItemDrop {
+                        depth : map.depth,
+                        id : network_id.id,
+                        item : item_name.clone(),
+                        idx : xy_idx(dropper_pos.x, dropper_pos.y) as i32,
+                        name : names.get(entity).map(|n| n.name.clone()).unwrap_or_default(),
+                    });
+                }
             }
         }
         wants_drop.clear();
@@ -1,29 +1,34 @@
 use specs::prelude::*;
 
-use crate::{CombatStats, WantsToMelee, SufferDamage, Name, GameLog, MeleePowerBonus, DefenseBonus, Equipped};
+use crate::{CombatStats, WantsToMelee, SufferDamage, Name, GameLog, MeleePowerBonus, DefenseBonus, Equipped, Enemy, GameMode, AttackerKind};
 
-/// Searches for entities trying to melee and counts the damage inflicted
+/// Searches for entities trying to melee and counts the damage inflicted.
+/// The player of the co-op game can't hurt other players, the monsters still can
 pub struct MeleeCombatSystem {}
 
 
 impl<'a> System<'a> for MeleeCombatSystem {
+    #[allow(clippy::type_complexity)]
     type SystemData = ( Entities<'a>,
                         WriteExpect<'a, GameLog>,
+                        ReadExpect<'a, GameMode>,
                         WriteStorage<'a, WantsToMelee>,
                         ReadStorage<'a, Name>,
                         ReadStorage<'a, CombatStats>,
                         WriteStorage<'a, SufferDamage>,
                         ReadStorage<'a, MeleePowerBonus>,
                         ReadStorage<'a, DefenseBonus>,
-                        ReadStorage<'a, Equipped>
+                        ReadStorage<'a, Equipped>,
+                        ReadStorage<'a, Enemy>,
+                        ReadExpect<'a, Entity>,
                     );
             
     fn run(&mut self, data : Self::SystemData) {
-        let (entities, mut log, 
+        let (entities, mut log, mode,
             mut wants_melee, names, 
             combat_stats, mut inflict_damage, 
             melee_power_bonuses, defense_bonuses, 
-            equipped) = data;
+            equipped, enemies, player_entity) = data;
         
         for (entity, wants_melee, name, stats) in (&entities, &wants_melee, &names, &combat_stats).join() {
             if stats.hp > 0 {
@@ -35,6 +40,11 @@ impl<'a> System<'a> for MeleeCombatSystem {
                     }
                 }
 
+                if entity == *player_entity && *mode == GameMode::Coop && enemies.contains(wants_melee.target) {
+                    log.entries.push(format!("{} is your ally", &names.get(wants_melee.target).unwrap().name));
+                    continue;
+                }
+
                 // searches for entities that trying to melee
                 let target_stats = combat_stats.get(wants_melee.target).unwrap();
                 if target_stats.hp > 0 {
@@ -52,7 +62,8 @@ impl<'a> System<'a> for MeleeCombatSystem {
                         log.entries.push(format!("{} is unable to hurt {}", &name.name, &target_name.name));
                     } else {
                         log.entries.push(format!("{} hits {}, for {} hp.", &name.name, &target_name.name, damage));
-                        SufferDamage::new_damage(&mut inflict_damage, wants_melee.target, damage);
+                        let kind = if entity == *player_entity { AttackerKind::Player } else { AttackerKind::Monster };
+                        SufferDamage::new_damage(&mut inflict_damage, wants_melee.target, damage, Some((name.name.clone(), kind)));
                     }
                 }
             }
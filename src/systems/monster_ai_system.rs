@@ -1,16 +1,19 @@
 use specs::prelude::*;
 
-use crate::{Viewshed, Monster, Map, Position, xy_idx, RunState, WantsToMelee, Confusion};
+use crate::{Viewshed, Monster, Map, Position, xy_idx, RunState, WantsToMelee, Confusion, Enemy, NetworkId, ClientHandler, ProtocolMessage};
 use rltk::Point;
 
 
-/// Responsible for pursuing a player
-pub struct MonsterAI {}
+/// Responsible for pursuing the players.
+/// Only the authority of the depth runs it, the moves of the shared monsters are sent to the other players
+pub struct MonsterAI<'a> {
+    pub is_authority : bool,
+    pub game_client : &'a mut ClientHandler,
+}
 
-impl<'a> System<'a> for MonsterAI {
+impl<'a> System<'a> for MonsterAI<'a> {
     #[allow(clippy::type_complexity)]
     type SystemData = ( WriteExpect<'a, Map>,
-                        ReadExpect<'a, Point>,
                         ReadExpect<'a, Entity>,
                         ReadExpect<'a, RunState>,
                         Entities<'a>,
@@ -19,20 +22,28 @@ impl<'a> System<'a> for MonsterAI {
                         WriteStorage<'a, Position>,
                         WriteStorage<'a, WantsToMelee>,
                         WriteStorage<'a, Confusion>,
+                        ReadStorage<'a, Enemy>,
+                        ReadStorage<'a, NetworkId>,
                     );
-    
+
     fn run(&mut self, data : Self::SystemData) {
-        let (mut map, player_pos, 
-            player_entity, runstate, 
-            entities, mut viewshed, 
-            monster, mut position, 
-            mut wants_to_melee, mut confused) = data;
+        let (mut map, player_entity,
+            runstate, entities,
+            mut viewshed, monster,
+            mut position, mut wants_to_melee,
+            mut confused, enemies, network_ids) = data;
 
-        if *runstate != RunState::MonsterTurn {
+        if *runstate != RunState::MonsterTurn || !self.is_authority {
             return;
         }
 
-        for (entity, mut viewshed, _monster, mut pos) in (&entities, &mut viewshed, &monster, &mut position).join() {
+        // the local player and the players from other computers on this depth
+        let targets = (&entities, &position).join()
+            .filter(|(entity, _)| *entity == *player_entity || enemies.contains(*entity))
+            .map(|(entity, pos)| (entity, Point::new(pos.x, pos.y)))
+            .collect::<Vec<_>>();
+
+        for (entity, viewshed, _monster, pos) in (&entities, &mut viewshed, &monster, &mut position).join() {
             let mut can_act = true;
 
             // skip turns if get confused
@@ -44,19 +55,29 @@ impl<'a> System<'a> for MonsterAI {
                 }
                 can_act = false;
             }
-            
-            // count distance to the player and move to him
+
+            // count distance to the nearest player and move to them
             if can_act {
-                let distance = rltk::DistanceAlg::Pythagoras.distance2d(Point::new(pos.x, pos.y), *player_pos);
+                let nearest = targets.iter()
+                    .map(|(target, target_pos)| {
+                        (*target, *target_pos, rltk::DistanceAlg::Pythagoras.distance2d(Point::new(pos.x, pos.y), *target_pos))
+                    })
+                    .filter(|(_, target_pos, distance)| *distance < 1.5 || viewshed.visible_tiles.contains(target_pos))
+                    .min_by(|a, b| a.2.total_cmp(&b.2));
+
+                let (target, target_pos, distance) = match nearest {
+                    Some(nearest) => nearest,
+                    None => continue,
+                };
 
                 if distance < 1.5 {
-                    wants_to_melee.insert(entity, WantsToMelee { target: *player_entity }).expect("Unable to insert attack");
-                } else if viewshed.visible_tiles.contains(&*player_pos) {
+                    wants_to_melee.insert(entity, WantsToMelee { target }).expect("Unable to insert attack");
+                } else {
                     // Path to the player
                     let path = rltk::a_star_search(
                         xy_idx(pos.x, pos.y),
-                        xy_idx(player_pos.x, player_pos.y),
-                        &mut *map
+                        xy_idx(target_pos.x, target_pos.y),
+                        &*map
                     );
 
                     if path.success && path.steps.len() > 1 {
@@ -67,6 +88,10 @@ impl<'a> System<'a> for MonsterAI {
                         idx = xy_idx(pos.x, pos.y);
                         map.blocked[idx] = true;
                         viewshed.dirty = true;
+
+                        if let Some(network_id) = network_ids.get(entity) {
+                            self.game_client.send_message(&ProtocolMessage::EntityMove { depth : map.depth, id : network_id.id, idx : idx as i32 });
+                        }
                     }
                 }
             }
@@ -7,6 +7,7 @@ use std::path::Path;
 
 use crate::constants::*;
 use crate::components::*;
+use crate::level_store::LevelStore;
 
 
 /// Macros for serializing components
@@ -28,11 +29,12 @@ macro_rules! serialize_individually {
 /// Serializing game components and save them in savegame.json
 pub fn save_game(ecs : &mut World) {
 
-    // create helper
+    // create helper, the left levels are saved with the current one
     let mapcopy = ecs.get_mut::<crate::map::Map>().unwrap().clone();
+    let levels = ecs.get_mut::<LevelStore>().unwrap().clone();
     let savehelper = ecs
         .create_entity()
-        .with(SerializationHelper{ map : mapcopy })
+        .with(SerializationHelper{ map : mapcopy, levels })
         .marked::<SimpleMarker<SerializeMe>>()
         .build();
 
@@ -45,7 +47,8 @@ pub fn save_game(ecs : &mut World) {
         serialize_individually!(ecs, serializer, data, Position, Renderable, Player, Viewshed, Monster,
             Name, BlocksTile, CombatStats, SufferDamage, WantsToMelee, Item, Consumable, Ranged, InflictDamage,
             AreaOfEffect, Confusion, ProvidesHealing, InBackpack, WantsToPickupItem, WantsToUseItem,
-            WantsToDropItem, SerializationHelper, Equippable, Equipped, MeleePowerBonus, DefenseBonus
+            WantsToDropItem, SerializationHelper, Equippable, Equipped, MeleePowerBonus, DefenseBonus, NetworkId,
+            OtherLevelPosition
         );
     }
 
@@ -54,38 +57,6 @@ pub fn save_game(ecs : &mut World) {
 }
 
 
-/// Serializing components for map and return stringful json
-pub fn save_map(ecs : &mut World) -> String {
-    // Create helper
-    let mapcopy = ecs.get_mut::<crate::map::Map>().unwrap().clone();
-    let savehelper = ecs
-        .create_entity()
-        .with(SerializationHelper{ map : mapcopy })
-        .marked::<SimpleMarker<SerializeMe>>()
-        .build();
-
-    // actually serialize
-    {  
-        let data = ( ecs.entities(), ecs.read_storage::<SimpleMarker<SerializeMe>>() );
-        let writer = File::create("./savemap.json").unwrap();
-        let mut serializer = serde_json::Serializer::new(writer);
-        serialize_individually!(ecs, serializer, data, Position, Renderable, Player, Viewshed, Monster,
-            Name, BlocksTile, CombatStats, SufferDamage, WantsToMelee, Item, Consumable, Ranged, InflictDamage,
-            AreaOfEffect, Confusion, ProvidesHealing, WantsToPickupItem, WantsToUseItem,
-            WantsToDropItem, SerializationHelper, Equippable, MeleePowerBonus, DefenseBonus
-        );
-    }
-
-    let result = fs::read_to_string("./savemap.json").expect("Can't open file");
-    std::fs::remove_file("./savemap.json").expect("Unable to delete file");
-
-    // clean up
-    ecs.delete_entity(savehelper).expect("Crash on cleanup");
-
-    result
-}
-
-
 /// Check if savegame.json exists
 pub fn does_save_exist() -> bool {
     Path::new("./savegame.json").exists()
@@ -132,7 +103,8 @@ pub fn load_game(ecs : &mut World) {
         deserialize_individually!(ecs, de, d, Position, Renderable, Player, Viewshed, Monster,
             Name, BlocksTile, CombatStats, SufferDamage, WantsToMelee, Item, Consumable, Ranged, InflictDamage,
             AreaOfEffect, Confusion, ProvidesHealing, InBackpack, WantsToPickupItem, WantsToUseItem,
-            WantsToDropItem, SerializationHelper, Equippable, Equipped, MeleePowerBonus, DefenseBonus
+            WantsToDropItem, SerializationHelper, Equippable, Equipped, MeleePowerBonus, DefenseBonus, NetworkId,
+            OtherLevelPosition
         );
     }
 
@@ -146,6 +118,7 @@ pub fn load_game(ecs : &mut World) {
             let mut worldmap = ecs.write_resource::<crate::map::Map>();
             *worldmap = h.map.clone();
             worldmap.tile_content = vec![Vec::new(); MAPCOUNT];
+            *ecs.write_resource::<LevelStore>() = h.levels.clone();
             deleteme = Some(e);
         }
         for (e,_p,pos) in (&entities, &player, &position).join() {
@@ -166,54 +139,3 @@ pub fn delete_save() {
         std::fs::remove_file("./savegame.json").expect("Unable to delete file");
     }
 }
-
-
-/// Deserialize components and load map
-pub fn load_map(ecs : &mut World, new_map : String) {
-    {
-        // Delete everything
-        let mut to_delete = Vec::new();
-        for e in ecs.entities().join() {
-            to_delete.push(e);
-        }
-        for del in to_delete.iter() {
-            ecs.delete_entity(*del).expect("Deletion failed");
-        }
-    }
-
-    let data = new_map;
-
-    let mut de = serde_json::Deserializer::from_str(&data);
-
-    {
-        let mut d = (&mut ecs.entities(), &mut ecs.write_storage::<SimpleMarker<SerializeMe>>(), &mut ecs.write_resource::<SimpleMarkerAllocator<SerializeMe>>());
-
-        deserialize_individually!(ecs, de, d, Position, Renderable, Player, Viewshed, Monster,
-            Name, BlocksTile, CombatStats, SufferDamage, WantsToMelee, Item, Consumable, Ranged, InflictDamage,
-            AreaOfEffect, Confusion, ProvidesHealing, WantsToPickupItem, WantsToUseItem,
-            WantsToDropItem, SerializationHelper, Equippable, MeleePowerBonus, DefenseBonus
-        );
-    }
-
-    let mut deleteme : Option<Entity> = None;
-    {
-        let entities = ecs.entities();
-        let helper = ecs.read_storage::<SerializationHelper>();
-        let player = ecs.read_storage::<Player>();
-        let position = ecs.read_storage::<Position>();
-        for (e,h) in (&entities, &helper).join() {
-            let mut worldmap = ecs.write_resource::<crate::map::Map>();
-            *worldmap = h.map.clone();
-            worldmap.tile_content = vec![Vec::new(); MAPCOUNT];
-            deleteme = Some(e);
-        }
-        for (e,_p,pos) in (&entities, &player, &position).join() {
-            let mut ppos = ecs.write_resource::<rltk::Point>();
-            *ppos = rltk::Point::new(pos.x, pos.y);
-            let mut player_resource = ecs.write_resource::<Entity>();
-            *player_resource = e;
-        }
-    }
-
-    ecs.delete_entity(deleteme.unwrap()).expect("Unable to delete helper");
-}
@@ -0,0 +1,55 @@
+// Checks of the messages that the games report about themselves.
+// The server uses them to refuse a modified game, the games use them for the messages of other players
+
+use super::map::{Map, TileType, idx_xy};
+use super::constants::*;
+
+
+/// The move goes to a tile of the level that is not a wall, one step away from the previous tile.
+/// The previous tile is unknown after the restart of the server, then any free tile is taken
+pub fn check_move(map : &Map, from : Option<i32>, to : i32) -> Result<(), String> {
+    if to < 0 || to >= map.width * map.height {
+        return Err(format!("The tile {} is out of the level", to));
+    }
+    if map.tiles[to as usize] == TileType::Wall {
+        return Err(format!("The tile {} is a wall", to));
+    }
+
+    if let Some(from) = from {
+        let (from_x, from_y) = idx_xy(from);
+        let (to_x, to_y) = idx_xy(to);
+        if (from_x - to_x).abs() > 1 || (from_y - to_y).abs() > 1 {
+            return Err(format!("The tile {} is more than one step away from {}", to, from));
+        }
+    }
+    Ok(())
+}
+
+/// The hp is not above the given maximum
+pub fn check_hp(hp : i32, max_hp : i32) -> Result<(), String> {
+    if hp > max_hp {
+        return Err(format!("The hp {} is above the maximum {}", hp, max_hp));
+    }
+    Ok(())
+}
+
+/// The target is not farther than the farthest spell reaches. The tiles are unknown after the restart of the server,
+/// then the hit is taken
+pub fn check_reach(from : Option<i32>, to : Option<i32>) -> Result<(), String> {
+    if let (Some(from), Some(to)) = (from, to) {
+        let (from_x, from_y) = idx_xy(from);
+        let (to_x, to_y) = idx_xy(to);
+        if (from_x - to_x).abs() > MAX_HIT_DISTANCE || (from_y - to_y).abs() > MAX_HIT_DISTANCE {
+            return Err(format!("The tile {} is out of reach from {}", to, from));
+        }
+    }
+    Ok(())
+}
+
+/// The hit is not stronger than the strongest weapon or spell of the game
+pub fn check_hit(amount : i32) -> Result<(), String> {
+    if !(0..=MAX_HIT).contains(&amount) {
+        return Err(format!("The hit for {} hp is impossible, the strongest one is {}", amount, MAX_HIT));
+    }
+    Ok(())
+}
@@ -0,0 +1,91 @@
+// Settings of the game: the json config file is read first, the command line arguments override it
+
+use std::fs;
+use std::path::PathBuf;
+
+use multiplayer_roguelike::*;
+
+
+/// The config file of the test, the name of the test keeps the parallel tests apart
+fn config_file(test : &str, json : &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("roguelike-config-{}-{}.txt", test, std::process::id()));
+    fs::write(&path, json).expect("Can't write config");
+    path
+}
+
+
+fn args(list : &[&str]) -> impl Iterator<Item = String> {
+    list.iter().map(|arg| arg.to_string()).collect::<Vec<_>>().into_iter()
+}
+
+
+#[test]
+/// The arguments win over the file, the rest of the file stays and the missing fields get the defaults
+fn arguments_override_the_file() {
+    let path = config_file("override", r#"{
+        "server" : "ws://file.example:7000",
+        "name" : "filename",
+        "seed" : 1,
+        "lag_ms" : 100,
+        "drop_rate" : 0.5,
+        "wall" : "%"
+    }"#);
+    let file = path.to_string_lossy().into_owned();
+
+    let config = Config::from_args(args(&["--config", &file, "--name", "cli", "--lag", "7", "--seed", "2"]))
+        .expect("Can't read config");
+    fs::remove_file(&path).ok();
+
+    assert_eq!(config.name.as_deref(), Some("cli"));
+    assert_eq!(config.seed, Some(2));
+    assert_eq!(config.network.lag_ms, 7);
+
+    assert_eq!(config.server, "ws://file.example:7000");
+    assert_eq!(config.network.drop_rate, 0.5);
+    assert_eq!(config.tiles.wall, '%');
+
+    assert!(!config.offline);
+    assert_eq!(config.network.jitter_ms, 0);
+    assert_eq!(config.tiles.floor, CustomizeTiles::default().floor);
+}
+
+
+#[test]
+/// The order of the arguments doesn't matter, the file is read before any of them is applied
+fn config_file_after_arguments() {
+    let path = config_file("order", r#"{ "server" : "ws://file.example:7000", "offline" : false }"#);
+    let file = path.to_string_lossy().into_owned();
+
+    let config = Config::from_args(args(&["--server", "ws://cli.example:7001", "--offline", "--config", &file]))
+        .expect("Can't read config");
+    fs::remove_file(&path).ok();
+
+    assert_eq!(config.server, "ws://cli.example:7001");
+    assert!(config.offline);
+}
+
+
+#[test]
+/// The wrong file or the wrong argument is an error, not a silently changed setting
+fn wrong_settings_are_errors() {
+    let path = config_file("wrong", r#"{ "server" : "not an address" }"#);
+    let file = path.to_string_lossy().into_owned();
+
+    assert!(Config::from_args(args(&["--config", &file])).is_err());
+    assert!(Config::from_args(args(&["--config", &file, "--server", "ws://127.0.0.1:6881"])).is_ok());
+    fs::remove_file(&path).ok();
+
+    let path = config_file("broken", "{ not json");
+    let file = path.to_string_lossy().into_owned();
+    assert!(Config::from_args(args(&["--config", &file])).is_err());
+    fs::remove_file(&path).ok();
+
+    let error = Config::from_args(args(&["--config", "/nonexistent/customize.txt"])).err().unwrap_or_default();
+    assert!(error.starts_with("Can't read config"), "{}", error);
+
+    let path = config_file("rate", r#"{ "drop_rate" : 0.1 }"#);
+    let file = path.to_string_lossy().into_owned();
+    let error = Config::from_args(args(&["--config", &file, "--drop", "2"])).err();
+    fs::remove_file(&path).ok();
+    assert_eq!(error.as_deref(), Some("--drop has to be from 0 to 1"));
+}
@@ -0,0 +1,22 @@
+// Levels built from the seeds: every depth below the first one has both stairs on different tiles
+
+use rltk::RandomNumberGenerator;
+
+use multiplayer_roguelike::*;
+
+
+#[test]
+/// The up stairs on the start never take the place of the only down stairs
+fn deeper_levels_have_both_stairs() {
+    for seed in 0..50 {
+        for depth in 2..=4 {
+            let mut rng = RandomNumberGenerator::seeded(level_seed(seed, depth));
+            let level = map_builders::build_level(depth, &mut rng, &[]);
+
+            let up = level.map.find_tile(TileType::UpStairs);
+            let down = level.map.find_tile(TileType::DownStairs);
+            assert_eq!(up, Some(level.start_idx() as usize), "No up stairs on the start, seed {} depth {}", seed, depth);
+            assert!(down.is_some(), "No down stairs, seed {} depth {}", seed, depth);
+        }
+    }
+}
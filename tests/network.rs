@@ -0,0 +1,61 @@
+// The simulated bad network in front of the scripted server: the same seed delays, loses and reorders the same messages
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use multiplayer_roguelike::*;
+use multiplayer_roguelike::backends::lagging_backend::{LaggingBackend, NetworkConditions};
+use multiplayer_roguelike::backends::recording_backend::{Direction, RecordedMessage};
+use multiplayer_roguelike::backends::scripted_backend::ScriptedBackend;
+
+
+/// Number of the moves the scripted server sends
+const MOVES : u32 = 60;
+
+const CONDITIONS : NetworkConditions = NetworkConditions {
+    lag_ms : 5,
+    jitter_ms : 20,
+    drop_rate : 0.2,
+    reorder_rate : 0.2,
+    network_seed : None,
+};
+
+
+/// Numbers of the moves in the order the game gets them through the bad network of the seed
+fn delivered(seed : u64) -> Vec<u32> {
+    let script = (1..=MOVES)
+        .map(|seq| RecordedMessage {
+            at_ms : 0,
+            direction : Direction::Received,
+            message : ProtocolMessage::Move { name : "bob".to_string(), idx : seq as i32, depth : 1, seq },
+        })
+        .collect();
+    let conditions = NetworkConditions { network_seed : Some(seed), ..CONDITIONS };
+    let mut backend = LaggingBackend::new(Box::new(ScriptedBackend::new(script)), conditions);
+
+    // every message is due after the lag, the worst jitter and the hold back of the reordering
+    let worst = Duration::from_millis(2 * (CONDITIONS.lag_ms + CONDITIONS.jitter_ms) + 1);
+    let started = Instant::now();
+    let mut seqs = Vec::new();
+    while started.elapsed() < worst * 2 {
+        for msg in backend.receive() {
+            if let ProtocolMessage::Move { seq, .. } = msg {
+                seqs.push(seq);
+            }
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+    seqs
+}
+
+
+#[test]
+/// The same seed spoils the same messages the same way, another seed spoils others
+fn same_seed_same_network() {
+    let first = delivered(7);
+
+    assert!(first.len() < MOVES as usize, "No message is lost");
+    assert!(first.windows(2).any(|pair| pair[0] > pair[1]), "No message is reordered");
+    assert_eq!(delivered(7), first);
+    assert_ne!(delivered(8), first);
+}
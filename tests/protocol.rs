@@ -0,0 +1,30 @@
+// Messages of the protocol survive the json: the names with spaces, quotes and backslashes come back as they were sent
+
+use multiplayer_roguelike::*;
+
+
+/// Names the old space separated messages couldn't carry
+const NAMES : &[&str] = &["Sir Robin", "\"Quoted\"", "O'Brien the \"Brave\"", "back\\slash", "  ", "{\"__MOVE__\":1}"];
+
+
+#[test]
+/// Every message with a player name decodes to the same message
+fn names_with_spaces_and_quotes_round_trip() {
+    for name in NAMES {
+        let name = name.to_string();
+        let messages = vec![
+            ProtocolMessage::IsName { name : name.clone(), accepted : true },
+            ProtocolMessage::Move { name : name.clone(), idx : 1234, depth : 2, seq : 7 },
+            ProtocolMessage::Damage { name : name.clone(), hp : 13 },
+            ProtocolMessage::Change { name : name.clone(), depth : 3 },
+            ProtocolMessage::TrackMe { name : name.clone(), depth : 4 },
+            ProtocolMessage::Chat { name : name.clone(), text : format!("{} says \"hi\"", name), depth : Some(1) },
+        ];
+
+        for msg in messages {
+            let data = msg.encode();
+            let decoded = ProtocolMessage::decode(&data).expect("Can't decode message");
+            assert_eq!(decoded, msg, "The message {} has changed on the way", data);
+        }
+    }
+}
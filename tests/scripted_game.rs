@@ -0,0 +1,358 @@
+// Multiplayer flows of the game played against the scripted server: the handshake, the server names the player, other players join, move, leave and die,
+// the game reports the death of its own player with the deepest level of the game
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use rltk::RandomNumberGenerator;
+use specs::prelude::*;
+
+use multiplayer_roguelike::*;
+use multiplayer_roguelike::leaderboard::RatingColumn;
+use multiplayer_roguelike::backends::recording_backend::{Direction, RecordedMessage};
+use multiplayer_roguelike::backends::scripted_backend::{ScriptedBackend, ScriptReport};
+
+
+/// Seed of every game of the tests, the levels are the same on every run
+const SEED : u64 = 42;
+
+const PLAYER : &str = "alice";
+const OTHER : &str = "bob";
+
+
+/// Game of the named player in a multiplayer room, the server is played by the script
+fn game(script : Vec<RecordedMessage>) -> (State, Arc<Mutex<ScriptReport>>) {
+    let config = Config { seed : Some(SEED), name : Some(PLAYER.to_string()), ..Config::default() };
+    let mut gs = State::new(config, Vec::new());
+
+    let backend = ScriptedBackend::new(script);
+    let report = backend.report();
+    gs.game_client = ClientHandler::new(Box::new(backend));
+    gs.play_mode = Some(PlayMode::Multiplayer);
+    gs.player_name = PLAYER.to_string();
+    (gs, report)
+}
+
+
+/// One tick of the game without the screen
+fn step(gs : &mut State) {
+    gs.receive_messages();
+    gs.run_systems();
+    gs.bury_the_dead();
+    gs.game_client.messages.clear();
+}
+
+
+/// The first level of the test seed and the tiles around its start, the other player walks on them
+fn first_level() -> (i32, Vec<i32>) {
+    let mut rng = RandomNumberGenerator::seeded(level_seed(SEED, 1));
+    let level = map_builders::build_level(1, &mut rng, &[]);
+    let start = level.start_idx();
+    let width = MAPWIDTH as i32;
+    let near = [-width - 1, -width, -width + 1, -1, 1, width - 1, width, width + 1]
+        .iter()
+        .map(|offset| start + offset)
+        .filter(|idx| validation::check_move(&level.map, None, *idx).is_ok())
+        .collect::<Vec<_>>();
+    assert!(near.len() >= 2, "The start of the test level is too narrow");
+    (start, near)
+}
+
+
+fn sent(message : ProtocolMessage) -> RecordedMessage {
+    RecordedMessage { at_ms : 0, direction : Direction::Sent, message }
+}
+
+
+fn received(message : ProtocolMessage) -> RecordedMessage {
+    RecordedMessage { at_ms : 0, direction : Direction::Received, message }
+}
+
+
+/// The server accepts the name of the player, the game comes to the first depth and tells the server about it
+fn arrival(start : i32) -> Vec<RecordedMessage> {
+    vec![
+        sent(ProtocolMessage::IsName { name : PLAYER.to_string(), accepted : false }),
+        received(ProtocolMessage::IsName { name : PLAYER.to_string(), accepted : true }),
+        sent(ProtocolMessage::TrackMe { name : PLAYER.to_string(), depth : 1 }),
+        sent(ProtocolMessage::Move { name : PLAYER.to_string(), idx : start, depth : 1, seq : 1 }),
+    ]
+}
+
+
+fn other_move(idx : i32, seq : u32) -> ProtocolMessage {
+    ProtocolMessage::Move { name : OTHER.to_string(), idx, depth : 1, seq }
+}
+
+
+/// The server sends the messages in one tick. The script gives all its received messages at once,
+/// so every tick gets its own script
+fn deliver(gs : &mut State, messages : Vec<ProtocolMessage>) -> Arc<Mutex<ScriptReport>> {
+    let backend = ScriptedBackend::new(messages.into_iter().map(received).collect());
+    let report = backend.report();
+    gs.game_client = ClientHandler::new(Box::new(backend));
+    step(gs);
+    report
+}
+
+
+/// The player plays as a guest, the game asks the server for the name and the player takes it
+fn name_player(gs : &mut State) {
+    let pending = gs.game_client.request(ProtocolMessage::IsName { name : PLAYER.to_string(), accepted : false });
+    gs.receive_messages();
+    let reply = match pending.poll(&mut gs.game_client) {
+        Reply::Ready(reply) => reply,
+        reply => panic!("The script doesn't answer the name: {:?}", reply),
+    };
+    assert!(gs.name_checked(reply) == RunState::Lobby { selection : 0 });
+}
+
+
+/// Game that has come to the first depth, the start tile and the tiles around it
+fn arrived() -> (State, i32, Vec<i32>) {
+    let (start, near) = first_level();
+    let (mut gs, report) = game(arrival(start));
+    name_player(&mut gs);
+    gs.goto_level(1);
+    step(&mut gs);
+    assert_played(&report);
+    (gs, start, near)
+}
+
+
+/// Tile of the other player and its hp, nothing if the game doesn't show the player
+fn other_player(gs : &State) -> Option<(i32, i32)> {
+    let entities = gs.ecs.entities();
+    let enemies = gs.ecs.read_storage::<Enemy>();
+    let names = gs.ecs.read_storage::<Name>();
+    let positions = gs.ecs.read_storage::<Position>();
+    let stats = gs.ecs.read_storage::<CombatStats>();
+    (&entities, &enemies, &names, &positions).join()
+        .find(|(_, _, name, _)| name.name == OTHER)
+        .map(|(entity, _, _, pos)| (xy_idx(pos.x, pos.y) as i32, stats.get(entity).map(|s| s.hp).unwrap_or_default()))
+}
+
+
+/// The whole script is played and the game has sent only what it expects
+fn assert_played(report : &Arc<Mutex<ScriptReport>>) {
+    let report = report.lock().unwrap();
+    assert!(report.unexpected.is_empty(), "Unexpected messages: {:?}", report.unexpected);
+    assert_eq!(report.remaining, 0, "The script isn't played to the end");
+}
+
+
+fn last_log(gs : &State) -> String {
+    gs.ecs.fetch::<GameLog>().entries.last().cloned().unwrap_or_default()
+}
+
+
+/// Game that has connected and waits for the answer of the server to its hello
+fn handshake(reply : ProtocolMessage) -> State {
+    let (mut gs, report) = game(vec![received(reply)]);
+    gs.pending = Some(ClientHandler::handshake(ProtocolMessage::hello(0)));
+    gs.ecs.insert(RunState::AwaitingHandshake);
+    gs.receive_messages();
+    assert_played(&report);
+    gs
+}
+
+
+/// The answer of the server with the given version and capabilities
+fn server_hello(version : u32, capabilities : &[&str], error : Option<&str>) -> ProtocolMessage {
+    ProtocolMessage::Hello {
+        version,
+        capabilities : capabilities.iter().map(|c| c.to_string()).collect(),
+        error : error.map(str::to_string),
+        prefabs : Some(0),
+    }
+}
+
+
+/// The game is back in the multiplayer menu with the reason on the screen
+fn assert_refused(gs : &State, reason : &str) {
+    assert_eq!(gs.network_error.as_deref(), Some(reason));
+    assert!(gs.play_mode.is_none());
+    assert!(*gs.ecs.fetch::<RunState>() == RunState::MainMenu { menu_selection : MainMenuSelection::Multiplayer });
+}
+
+
+#[test]
+/// The server older than the oldest protocol the game speaks is refused by the game
+fn handshake_refuses_old_server() {
+    let gs = handshake(server_hello(MIN_PROTOCOL_VERSION - 1, CAPABILITIES, None));
+
+    assert_refused(&gs, &format!("The server is too old: protocol {}, the game needs at least {}",
+        MIN_PROTOCOL_VERSION - 1, MIN_PROTOCOL_VERSION));
+}
+
+
+#[test]
+/// The server that refuses the prefabs of the game sends the game back to the menu with its reason
+fn handshake_refuses_other_prefabs() {
+    let reason = "Your game has other prefabs than the server, its levels would differ. Please update the prefabs";
+    let gs = handshake(server_hello(PROTOCOL_VERSION, CAPABILITIES, Some(reason)));
+
+    assert_refused(&gs, reason);
+}
+
+
+#[test]
+/// The server without the chat still plays, only the capabilities both sides have are used
+fn handshake_without_capability() {
+    let mut gs = handshake(server_hello(PROTOCOL_VERSION, &[], None));
+
+    assert_eq!(gs.network_error, None);
+    assert!(!gs.game_client.has_capability(CHAT));
+    let reply = gs.pending.take().map(|pending| pending.poll(&mut gs.game_client));
+    assert!(matches!(reply, Some(Reply::Ready(ProtocolMessage::Hello { error : None, .. }))));
+
+    let gs = handshake(server_hello(PROTOCOL_VERSION, &[CHAT], None));
+    assert!(gs.game_client.has_capability(CHAT));
+}
+
+
+#[test]
+/// The other player is shown on the tile of the first move
+fn joined_player_is_shown() {
+    let (mut gs, _, near) = arrived();
+
+    let report = deliver(&mut gs, vec![other_move(near[0], 1)]);
+
+    assert_eq!(other_player(&gs), Some((near[0], PLAYER_MAX_HP)));
+    assert_eq!(gs.enemies, vec![OTHER.to_string()]);
+    assert_played(&report);
+}
+
+
+#[test]
+/// The moves are walked tile by tile, the late ones are dropped and the hp of the other player follows its reports
+fn moves_and_damage_of_other_player() {
+    let (mut gs, start, near) = arrived();
+    deliver(&mut gs, vec![other_move(near[0], 1)]);
+
+    deliver(&mut gs, vec![
+        other_move(near[1], 3),
+        other_move(near[0], 4),
+        // comes after the newer ones
+        other_move(start, 2),
+        ProtocolMessage::Damage { name : OTHER.to_string(), hp : 12 },
+    ]);
+    assert_eq!(other_player(&gs), Some((near[1], 12)));
+
+    thread::sleep(Duration::from_millis(MOVE_STEP_MS * 2));
+    step(&mut gs);
+    assert_eq!(other_player(&gs), Some((near[0], 12)));
+
+    thread::sleep(Duration::from_millis(MOVE_STEP_MS * 2));
+    step(&mut gs);
+    assert_eq!(other_player(&gs), Some((near[0], 12)), "The late move is walked");
+}
+
+
+#[test]
+/// The player that has disconnected is gone from the level and the log tells about it
+fn disconnected_player_is_removed() {
+    let (mut gs, _, near) = arrived();
+    deliver(&mut gs, vec![other_move(near[0], 1)]);
+    assert!(other_player(&gs).is_some());
+
+    deliver(&mut gs, vec![ProtocolMessage::Left { name : OTHER.to_string() }]);
+
+    assert_eq!(other_player(&gs), None);
+    assert!(gs.enemies.is_empty());
+    assert_eq!(last_log(&gs), format!("{} has left the game", OTHER));
+}
+
+
+#[test]
+/// The dead player leaves the corpse, its next game starts the moves anew
+fn dead_player_leaves_corpse() {
+    let (mut gs, _, near) = arrived();
+    deliver(&mut gs, vec![other_move(near[0], 7)]);
+
+    deliver(&mut gs, vec![ProtocolMessage::Died {
+        name : OTHER.to_string(),
+        depth : 1,
+        max_depth : 1,
+        kills : 0,
+        turns : 5,
+        cause : "Killed by Orc".to_string(),
+    }]);
+
+    assert_eq!(other_player(&gs), None);
+    assert_eq!(last_log(&gs), format!("{} has died", OTHER));
+    {
+        let names = gs.ecs.read_storage::<Name>();
+        let positions = gs.ecs.read_storage::<Position>();
+        let corpse = (&names, &positions).join().find(|(name, _)| name.name == format!("Corpse of {}", OTHER));
+        assert_eq!(corpse.map(|(_, pos)| xy_idx(pos.x, pos.y) as i32), Some(near[0]));
+    }
+
+    deliver(&mut gs, vec![other_move(near[1], 1)]);
+    assert_eq!(other_player(&gs), Some((near[1], PLAYER_MAX_HP)));
+}
+
+
+#[test]
+/// The death of the player goes to the server with the stats of the game, only once
+fn own_death_is_reported() {
+    let (start, _) = first_level();
+    let mut script = arrival(start);
+    script.push(sent(ProtocolMessage::Damage { name : PLAYER.to_string(), hp : 0 }));
+    script.push(sent(ProtocolMessage::Died {
+        name : PLAYER.to_string(),
+        depth : 1,
+        max_depth : 1,
+        kills : 0,
+        turns : 0,
+        cause : "Killed by Orc".to_string(),
+    }));
+    let (mut gs, report) = game(script);
+    name_player(&mut gs);
+    gs.goto_level(1);
+    step(&mut gs);
+
+    let player = *gs.ecs.fetch::<Entity>();
+    assert_eq!(gs.ecs.read_storage::<Name>().get(player).map(|name| name.name.clone()), Some(PLAYER.to_string()));
+    SufferDamage::new_damage(&mut gs.ecs.write_storage::<SufferDamage>(), player, PLAYER_MAX_HP, Some(("Orc".to_string(), AttackerKind::Monster)));
+    step(&mut gs);
+    assert!(*gs.ecs.fetch::<RunState>() == RunState::GameOver);
+
+    step(&mut gs);
+    assert_played(&report);
+}
+
+
+#[test]
+/// The player that goes up and dies there gets the deepest level of the game in the leaderboard
+fn leaderboard_keeps_deepest_level() {
+    let config = Config { seed : Some(SEED), name : Some(PLAYER.to_string()), ..Config::default() };
+    let mut gs = State::new(config, Vec::new());
+    gs.game_client = ClientHandler::local();
+    gs.play_mode = Some(PlayMode::SinglePlayer);
+    gs.player_name = PLAYER.to_string();
+
+    gs.goto_level(2);
+    gs.goto_level(1);
+    let player = *gs.ecs.fetch::<Entity>();
+    SufferDamage::new_damage(&mut gs.ecs.write_storage::<SufferDamage>(), player, PLAYER_MAX_HP, Some(("Orc".to_string(), AttackerKind::Monster)));
+    step(&mut gs);
+    assert!(*gs.ecs.fetch::<RunState>() == RunState::GameOver);
+
+    gs.game_client.send_message(&ProtocolMessage::Rating {
+        column : RatingColumn::MaxDepth,
+        descending : true,
+        page : 0,
+        room : None,
+        all_rooms : false,
+        entries : Vec::new(),
+        total : 0,
+    });
+    gs.game_client.get_messages();
+    let entries = gs.game_client.messages.iter().find_map(|msg| match msg {
+        ProtocolMessage::Rating { entries, .. } => Some(entries.clone()),
+        _ => None,
+    });
+    assert_eq!(entries.map(|entries| entries.iter().map(|e| e.max_depth).collect::<Vec<_>>()), Some(vec![2]));
+}